@@ -0,0 +1,260 @@
+#![no_std]
+
+use multiversx_price_aggregator_sc::{median, price_aggregator_proxy};
+use multiversx_sc::derive_imports::*;
+use multiversx_sc::imports::*;
+
+pub mod multi_source_aggregator_proxy;
+
+pub const MAX_SOURCES: usize = 10;
+pub const MAX_SOURCE_WEIGHT: u32 = 10;
+const PAUSED_ERROR_MSG: &[u8] = b"Contract is paused";
+
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, Clone, Debug, PartialEq, Eq)]
+pub struct SourceConfig {
+    pub weight: u32,
+    pub max_age_seconds: u64,
+}
+
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, Clone, Debug, PartialEq, Eq)]
+pub struct PublishedPrice<M: ManagedTypeApi> {
+    pub round_id: u32,
+    /// The timestamp of the oldest source price used.
+    pub timestamp: u64,
+    pub price: BigUint<M>,
+    pub decimals: u8,
+    pub source_count: usize,
+}
+
+/// Publishes the weighted median of the prices of several price aggregators,
+/// and serves it through the same `latestPriceFeed` view.
+#[multiversx_sc::contract]
+pub trait MultiSourceAggregator: multiversx_sc_modules::pause::PauseModule {
+    #[init]
+    fn init(&self, min_sources: usize) {
+        self.set_min_sources(min_sources);
+        self.set_paused(true);
+    }
+
+    #[upgrade]
+    fn upgrade(&self) {}
+
+    /// Sources are read through synchronous calls, so they must be deployed in the same shard.
+    #[only_owner]
+    #[endpoint(addSource)]
+    fn add_source(&self, address: ManagedAddress, weight: u32, max_age_seconds: u64) {
+        require!(
+            self.blockchain().is_smart_contract(&address),
+            "source must be a smart contract"
+        );
+        require!(weight > 0 && weight <= MAX_SOURCE_WEIGHT, "invalid weight");
+        require!(max_age_seconds > 0, "invalid max age");
+
+        let mut sources = self.sources();
+        require!(!sources.contains_key(&address), "source already added");
+        require!(sources.len() < MAX_SOURCES, "too many sources");
+
+        sources.insert(
+            address,
+            SourceConfig {
+                weight,
+                max_age_seconds,
+            },
+        );
+    }
+
+    #[only_owner]
+    #[endpoint(removeSource)]
+    fn remove_source(&self, address: ManagedAddress) {
+        require!(
+            self.sources().remove(&address).is_some(),
+            "source not found"
+        );
+    }
+
+    /// The minimum number of fresh sources needed to publish a price.
+    #[only_owner]
+    #[endpoint(setMinSources)]
+    fn set_min_sources(&self, min_sources: usize) {
+        require!(
+            min_sources > 0 && min_sources <= MAX_SOURCES,
+            "invalid min sources"
+        );
+
+        self.min_sources().set(min_sources);
+    }
+
+    /// Source prices are converted to these decimals before the median is computed.
+    #[only_owner]
+    #[endpoint(setPairDecimals)]
+    fn set_pair_decimals(&self, from: ManagedBuffer, to: ManagedBuffer, decimals: u8) {
+        self.pair_decimals(&from, &to).set(decimals);
+    }
+
+    /// Anyone can publish a new price for a configured pair.
+    /// Paused sources, sources without the pair and sources with a price older than their maximum age are skipped.
+    /// Fails if the oldest source price used is not newer than the published one, so an unchanged price is not republished.
+    #[endpoint(updatePrice)]
+    fn update_price(&self, from: ManagedBuffer, to: ManagedBuffer) {
+        require!(self.not_paused(), PAUSED_ERROR_MSG);
+
+        let decimals_mapper = self.pair_decimals(&from, &to);
+        require!(!decimals_mapper.is_empty(), "pair decimals not configured");
+        let decimals = decimals_mapper.get();
+
+        let current_timestamp = self.blockchain().get_block_timestamp();
+        let mut weighted_prices =
+            ArrayVec::<BigUint, { MAX_SOURCES * MAX_SOURCE_WEIGHT as usize }>::new();
+        let mut source_count = 0usize;
+        let mut oldest_timestamp = current_timestamp;
+        for (address, source) in self.sources().iter() {
+            let Some(price_feed) = self.read_source(&address, &from, &to) else {
+                continue;
+            };
+            if current_timestamp.saturating_sub(price_feed.timestamp) > source.max_age_seconds {
+                continue;
+            }
+
+            let price = scale_price(price_feed.price, price_feed.decimals, decimals);
+            for _ in 0..source.weight {
+                weighted_prices.push(price.clone());
+            }
+            source_count += 1;
+            oldest_timestamp = oldest_timestamp.min(price_feed.timestamp);
+        }
+        require!(
+            source_count >= self.min_sources().get(),
+            "not enough fresh sources"
+        );
+
+        let price = median::calculate(weighted_prices.as_mut_slice())
+            .unwrap_or_else(|err| sc_panic!(err.as_bytes()))
+            .unwrap_or_else(|| sc_panic!("no price"));
+        let price_mapper = self.latest_price(&from, &to);
+        let round_id = if price_mapper.is_empty() {
+            1
+        } else {
+            let last_published_price = price_mapper.get();
+            require!(
+                oldest_timestamp > last_published_price.timestamp,
+                "no new source price"
+            );
+
+            last_published_price.round_id + 1
+        };
+        let published_price = PublishedPrice {
+            round_id,
+            timestamp: oldest_timestamp,
+            price,
+            decimals,
+            source_count,
+        };
+        self.price_published_event(&from, &to, &published_price);
+        price_mapper.set(published_price);
+    }
+
+    fn read_source(
+        &self,
+        address: &ManagedAddress,
+        from: &ManagedBuffer,
+        to: &ManagedBuffer,
+    ) -> Option<price_aggregator_proxy::PriceFeed<Self::Api>> {
+        let is_paused = self
+            .tx()
+            .to(address)
+            .typed(price_aggregator_proxy::PriceAggregatorProxy)
+            .paused_status()
+            .returns(ReturnsResult)
+            .sync_call();
+        if is_paused {
+            return None;
+        }
+
+        let mut pairs = MultiValueEncoded::new();
+        pairs.push((from.clone(), to.clone()).into());
+        self.tx()
+            .to(address)
+            .typed(price_aggregator_proxy::PriceAggregatorProxy)
+            .latest_price_feeds(pairs)
+            .returns(ReturnsResult)
+            .sync_call()
+            .into_iter()
+            .next()
+            .flatten()
+    }
+
+    /// Same signature as the view of the price aggregator, so that consumers can read either contract.
+    #[view(latestPriceFeed)]
+    fn latest_price_feed(
+        &self,
+        from: ManagedBuffer,
+        to: ManagedBuffer,
+    ) -> MultiValue6<u32, ManagedBuffer, ManagedBuffer, u64, BigUint, u8> {
+        require!(self.not_paused(), PAUSED_ERROR_MSG);
+
+        let price_mapper = self.latest_price(&from, &to);
+        require!(!price_mapper.is_empty(), "token pair not found");
+
+        let published_price = price_mapper.get();
+        (
+            published_price.round_id,
+            from,
+            to,
+            published_price.timestamp,
+            published_price.price,
+            published_price.decimals,
+        )
+            .into()
+    }
+
+    #[view(getSources)]
+    fn get_sources(&self) -> MultiValueEncoded<MultiValue3<ManagedAddress, u32, u64>> {
+        let mut result = MultiValueEncoded::new();
+        for (address, source) in self.sources().iter() {
+            result.push((address, source.weight, source.max_age_seconds).into());
+        }
+
+        result
+    }
+
+    #[event("price_published")]
+    fn price_published_event(
+        &self,
+        #[indexed] from: &ManagedBuffer,
+        #[indexed] to: &ManagedBuffer,
+        published_price: &PublishedPrice<Self::Api>,
+    );
+
+    #[storage_mapper("sources")]
+    fn sources(&self) -> MapMapper<ManagedAddress, SourceConfig>;
+
+    #[view(getMinSources)]
+    #[storage_mapper("min_sources")]
+    fn min_sources(&self) -> SingleValueMapper<usize>;
+
+    #[view(getPairDecimals)]
+    #[storage_mapper("pair_decimals")]
+    fn pair_decimals(&self, from: &ManagedBuffer, to: &ManagedBuffer) -> SingleValueMapper<u8>;
+
+    #[view(getLatestPrice)]
+    #[storage_mapper("latest_price")]
+    fn latest_price(
+        &self,
+        from: &ManagedBuffer,
+        to: &ManagedBuffer,
+    ) -> SingleValueMapper<PublishedPrice<Self::Api>>;
+}
+
+fn scale_price<M: ManagedTypeApi>(
+    price: BigUint<M>,
+    from_decimals: u8,
+    to_decimals: u8,
+) -> BigUint<M> {
+    if from_decimals <= to_decimals {
+        price * BigUint::from(10u64).pow((to_decimals - from_decimals) as u32)
+    } else {
+        price / BigUint::from(10u64).pow((from_decimals - to_decimals) as u32)
+    }
+}
@@ -0,0 +1,280 @@
+// Code generated by the multiversx-sc proxy generator. DO NOT EDIT.
+
+////////////////////////////////////////////////////
+////////////////// AUTO-GENERATED //////////////////
+////////////////////////////////////////////////////
+
+#![allow(dead_code)]
+#![allow(clippy::all)]
+
+use multiversx_sc::proxy_imports::*;
+
+pub struct MultiSourceAggregatorProxy;
+
+impl<Env, From, To, Gas> TxProxyTrait<Env, From, To, Gas> for MultiSourceAggregatorProxy
+where
+    Env: TxEnv,
+    From: TxFrom<Env>,
+    To: TxTo<Env>,
+    Gas: TxGas<Env>,
+{
+    type TxProxyMethods = MultiSourceAggregatorProxyMethods<Env, From, To, Gas>;
+
+    fn proxy_methods(self, tx: Tx<Env, From, To, (), Gas, (), ()>) -> Self::TxProxyMethods {
+        MultiSourceAggregatorProxyMethods { wrapped_tx: tx }
+    }
+}
+
+pub struct MultiSourceAggregatorProxyMethods<Env, From, To, Gas>
+where
+    Env: TxEnv,
+    From: TxFrom<Env>,
+    To: TxTo<Env>,
+    Gas: TxGas<Env>,
+{
+    wrapped_tx: Tx<Env, From, To, (), Gas, (), ()>,
+}
+
+#[rustfmt::skip]
+impl<Env, From, Gas> MultiSourceAggregatorProxyMethods<Env, From, (), Gas>
+where
+    Env: TxEnv,
+    Env::Api: VMApi,
+    From: TxFrom<Env>,
+    Gas: TxGas<Env>,
+{
+    pub fn init<
+        Arg0: ProxyArg<usize>,
+    >(
+        self,
+        min_sources: Arg0,
+    ) -> TxTypedDeploy<Env, From, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_deploy()
+            .argument(&min_sources)
+            .original_result()
+    }
+}
+
+#[rustfmt::skip]
+impl<Env, From, To, Gas> MultiSourceAggregatorProxyMethods<Env, From, To, Gas>
+where
+    Env: TxEnv,
+    Env::Api: VMApi,
+    From: TxFrom<Env>,
+    To: TxTo<Env>,
+    Gas: TxGas<Env>,
+{
+    pub fn upgrade(
+        self,
+    ) -> TxTypedUpgrade<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_upgrade()
+            .original_result()
+    }
+}
+
+#[rustfmt::skip]
+impl<Env, From, To, Gas> MultiSourceAggregatorProxyMethods<Env, From, To, Gas>
+where
+    Env: TxEnv,
+    Env::Api: VMApi,
+    From: TxFrom<Env>,
+    To: TxTo<Env>,
+    Gas: TxGas<Env>,
+{
+    /// Sources are read through synchronous calls, so they must be deployed in the same shard. 
+    pub fn add_source<
+        Arg0: ProxyArg<ManagedAddress<Env::Api>>,
+        Arg1: ProxyArg<u32>,
+        Arg2: ProxyArg<u64>,
+    >(
+        self,
+        address: Arg0,
+        weight: Arg1,
+        max_age_seconds: Arg2,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("addSource")
+            .argument(&address)
+            .argument(&weight)
+            .argument(&max_age_seconds)
+            .original_result()
+    }
+
+    pub fn remove_source<
+        Arg0: ProxyArg<ManagedAddress<Env::Api>>,
+    >(
+        self,
+        address: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("removeSource")
+            .argument(&address)
+            .original_result()
+    }
+
+    /// The minimum number of fresh sources needed to publish a price. 
+    pub fn set_min_sources<
+        Arg0: ProxyArg<usize>,
+    >(
+        self,
+        min_sources: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("setMinSources")
+            .argument(&min_sources)
+            .original_result()
+    }
+
+    /// Source prices are converted to these decimals before the median is computed. 
+    pub fn set_pair_decimals<
+        Arg0: ProxyArg<ManagedBuffer<Env::Api>>,
+        Arg1: ProxyArg<ManagedBuffer<Env::Api>>,
+        Arg2: ProxyArg<u8>,
+    >(
+        self,
+        from: Arg0,
+        to: Arg1,
+        decimals: Arg2,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("setPairDecimals")
+            .argument(&from)
+            .argument(&to)
+            .argument(&decimals)
+            .original_result()
+    }
+
+    /// Anyone can publish a new price for a configured pair. 
+    /// Paused sources, sources without the pair and sources with a price older than their maximum age are skipped. 
+    /// Fails if the oldest source price used is not newer than the published one, so an unchanged price is not republished. 
+    pub fn update_price<
+        Arg0: ProxyArg<ManagedBuffer<Env::Api>>,
+        Arg1: ProxyArg<ManagedBuffer<Env::Api>>,
+    >(
+        self,
+        from: Arg0,
+        to: Arg1,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("updatePrice")
+            .argument(&from)
+            .argument(&to)
+            .original_result()
+    }
+
+    /// Same signature as the view of the price aggregator, so that consumers can read either contract. 
+    pub fn latest_price_feed<
+        Arg0: ProxyArg<ManagedBuffer<Env::Api>>,
+        Arg1: ProxyArg<ManagedBuffer<Env::Api>>,
+    >(
+        self,
+        from: Arg0,
+        to: Arg1,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, MultiValue6<u32, ManagedBuffer<Env::Api>, ManagedBuffer<Env::Api>, u64, BigUint<Env::Api>, u8>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("latestPriceFeed")
+            .argument(&from)
+            .argument(&to)
+            .original_result()
+    }
+
+    pub fn get_sources(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, MultiValueEncoded<Env::Api, MultiValue3<ManagedAddress<Env::Api>, u32, u64>>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getSources")
+            .original_result()
+    }
+
+    pub fn min_sources(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, usize> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getMinSources")
+            .original_result()
+    }
+
+    pub fn pair_decimals<
+        Arg0: ProxyArg<ManagedBuffer<Env::Api>>,
+        Arg1: ProxyArg<ManagedBuffer<Env::Api>>,
+    >(
+        self,
+        from: Arg0,
+        to: Arg1,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, u8> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getPairDecimals")
+            .argument(&from)
+            .argument(&to)
+            .original_result()
+    }
+
+    pub fn latest_price<
+        Arg0: ProxyArg<ManagedBuffer<Env::Api>>,
+        Arg1: ProxyArg<ManagedBuffer<Env::Api>>,
+    >(
+        self,
+        from: Arg0,
+        to: Arg1,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, PublishedPrice<Env::Api>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getLatestPrice")
+            .argument(&from)
+            .argument(&to)
+            .original_result()
+    }
+
+    pub fn pause_endpoint(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("pause")
+            .original_result()
+    }
+
+    pub fn unpause_endpoint(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("unpause")
+            .original_result()
+    }
+
+    pub fn paused_status(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, bool> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("isPaused")
+            .original_result()
+    }
+}
+
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, Clone, Debug, PartialEq, Eq)]
+pub struct PublishedPrice<Api>
+where
+    Api: ManagedTypeApi,
+{
+    pub round_id: u32,
+    pub timestamp: u64,
+    pub price: BigUint<Api>,
+    pub decimals: u8,
+    pub source_count: usize,
+}
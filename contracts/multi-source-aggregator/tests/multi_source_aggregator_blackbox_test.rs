@@ -0,0 +1,316 @@
+use multi_source_aggregator::multi_source_aggregator_proxy::MultiSourceAggregatorProxy;
+use multiversx_price_aggregator_sc::price_aggregator_proxy::PriceAggregatorProxy;
+use multiversx_sc_scenario::imports::*;
+
+const EGLD_TICKER: &[u8] = b"EGLD";
+const USD_TICKER: &[u8] = b"USDC";
+const DECIMALS: u8 = 2;
+
+const MULTI_SOURCE_AGGREGATOR_ADDRESS: TestSCAddress =
+    TestSCAddress::new("multi-source-aggregator");
+const SOURCE_A_ADDRESS: TestSCAddress = TestSCAddress::new("source-a");
+const SOURCE_B_ADDRESS: TestSCAddress = TestSCAddress::new("source-b");
+const SOURCE_C_ADDRESS: TestSCAddress = TestSCAddress::new("source-c");
+const OWNER_ADDRESS: TestAddress = TestAddress::new("owner");
+const USER_ADDRESS: TestAddress = TestAddress::new("user");
+const MULTI_SOURCE_AGGREGATOR_PATH: MxscPath =
+    MxscPath::new("output/multi-source-aggregator.mxsc.json");
+const PRICE_AGGREGATOR_PATH: MxscPath =
+    MxscPath::new("../price-aggregator/output/multiversx-price-aggregator-sc.mxsc.json");
+
+fn world() -> ScenarioWorld {
+    let mut blockchain = ScenarioWorld::new();
+
+    blockchain.register_contract(
+        MULTI_SOURCE_AGGREGATOR_PATH,
+        multi_source_aggregator::ContractBuilder,
+    );
+    blockchain.register_contract(
+        PRICE_AGGREGATOR_PATH,
+        multiversx_price_aggregator_sc::ContractBuilder,
+    );
+
+    blockchain
+}
+
+struct MultiSourceAggregatorTestState {
+    world: ScenarioWorld,
+}
+
+impl MultiSourceAggregatorTestState {
+    fn new() -> Self {
+        let mut world = world();
+
+        world.account(OWNER_ADDRESS).nonce(1);
+        world.account(USER_ADDRESS).nonce(1);
+        world.current_block().block_timestamp(100);
+
+        Self { world }
+    }
+
+    fn deploy(&mut self, min_sources: usize) -> &mut Self {
+        self.world
+            .tx()
+            .from(OWNER_ADDRESS)
+            .typed(MultiSourceAggregatorProxy)
+            .init(min_sources)
+            .code(MULTI_SOURCE_AGGREGATOR_PATH)
+            .new_address(MULTI_SOURCE_AGGREGATOR_ADDRESS)
+            .run();
+
+        self
+    }
+
+    /// A price aggregator serving a manual price for the pair.
+    fn deploy_source(&mut self, address: TestSCAddress, decimals: u8, price: u64) {
+        let oracles = MultiValueVec::from(vec![
+            TestAddress::new("oracle1").to_address(),
+            TestAddress::new("oracle2").to_address(),
+            TestAddress::new("oracle3").to_address(),
+        ]);
+        self.world
+            .tx()
+            .from(OWNER_ADDRESS)
+            .typed(PriceAggregatorProxy)
+            .init(
+                EgldOrEsdtTokenIdentifier::egld(),
+                1u64,
+                1u64,
+                3usize,
+                3usize,
+                oracles,
+            )
+            .code(PRICE_AGGREGATOR_PATH)
+            .new_address(address)
+            .run();
+        self.world
+            .tx()
+            .from(OWNER_ADDRESS)
+            .to(address)
+            .typed(PriceAggregatorProxy)
+            .set_pair_decimals(EGLD_TICKER, USD_TICKER, decimals)
+            .run();
+        self.world
+            .tx()
+            .from(OWNER_ADDRESS)
+            .to(address)
+            .typed(PriceAggregatorProxy)
+            .set_manual_price(EGLD_TICKER, USD_TICKER, price, 10_000u64)
+            .run();
+        self.world
+            .tx()
+            .from(OWNER_ADDRESS)
+            .to(address)
+            .typed(PriceAggregatorProxy)
+            .unpause_endpoint()
+            .run();
+    }
+
+    /// Serves the price again, dated by the current block.
+    fn refresh_source(&mut self, address: TestSCAddress, price: u64) {
+        self.world
+            .tx()
+            .from(OWNER_ADDRESS)
+            .to(address)
+            .typed(PriceAggregatorProxy)
+            .set_manual_price(EGLD_TICKER, USD_TICKER, price, 10_000u64)
+            .run();
+    }
+
+    fn add_source(&mut self, address: TestSCAddress, weight: u32, max_age_seconds: u64) {
+        self.world
+            .tx()
+            .from(OWNER_ADDRESS)
+            .to(MULTI_SOURCE_AGGREGATOR_ADDRESS)
+            .typed(MultiSourceAggregatorProxy)
+            .add_source(address, weight, max_age_seconds)
+            .run();
+    }
+
+    fn update_price(&mut self) {
+        self.world
+            .tx()
+            .from(USER_ADDRESS)
+            .to(MULTI_SOURCE_AGGREGATOR_ADDRESS)
+            .typed(MultiSourceAggregatorProxy)
+            .update_price(EGLD_TICKER, USD_TICKER)
+            .run();
+    }
+
+    fn update_price_and_expect_err(&mut self, err_message: &str) {
+        self.world
+            .tx()
+            .from(USER_ADDRESS)
+            .to(MULTI_SOURCE_AGGREGATOR_ADDRESS)
+            .typed(MultiSourceAggregatorProxy)
+            .update_price(EGLD_TICKER, USD_TICKER)
+            .with_result(ExpectError(4, err_message))
+            .run();
+    }
+
+    fn check_latest_price_feed(&mut self, round_id: u32, timestamp: u64, price: u64) {
+        self.world
+            .query()
+            .to(MULTI_SOURCE_AGGREGATOR_ADDRESS)
+            .typed(MultiSourceAggregatorProxy)
+            .latest_price_feed(EGLD_TICKER, USD_TICKER)
+            .returns(ExpectValue(MultiValue6::from((
+                round_id,
+                ManagedBuffer::<StaticApi>::from(EGLD_TICKER),
+                ManagedBuffer::<StaticApi>::from(USD_TICKER),
+                timestamp,
+                BigUint::<StaticApi>::from(price),
+                DECIMALS,
+            ))))
+            .run();
+    }
+}
+
+#[test]
+fn test_multi_source_aggregator_sources() {
+    let mut state = MultiSourceAggregatorTestState::new();
+    state.deploy(2);
+    state.deploy_source(SOURCE_A_ADDRESS, DECIMALS, 10_000);
+
+    state
+        .world
+        .tx()
+        .from(OWNER_ADDRESS)
+        .to(MULTI_SOURCE_AGGREGATOR_ADDRESS)
+        .typed(MultiSourceAggregatorProxy)
+        .add_source(USER_ADDRESS, 1u32, 60u64)
+        .with_result(ExpectError(4, "source must be a smart contract"))
+        .run();
+    state
+        .world
+        .tx()
+        .from(OWNER_ADDRESS)
+        .to(MULTI_SOURCE_AGGREGATOR_ADDRESS)
+        .typed(MultiSourceAggregatorProxy)
+        .add_source(SOURCE_A_ADDRESS, 11u32, 60u64)
+        .with_result(ExpectError(4, "invalid weight"))
+        .run();
+    state
+        .world
+        .tx()
+        .from(USER_ADDRESS)
+        .to(MULTI_SOURCE_AGGREGATOR_ADDRESS)
+        .typed(MultiSourceAggregatorProxy)
+        .add_source(SOURCE_A_ADDRESS, 1u32, 60u64)
+        .with_result(ExpectError(4, "Endpoint can only be called by owner"))
+        .run();
+
+    state.add_source(SOURCE_A_ADDRESS, 1, 60);
+    state
+        .world
+        .tx()
+        .from(OWNER_ADDRESS)
+        .to(MULTI_SOURCE_AGGREGATOR_ADDRESS)
+        .typed(MultiSourceAggregatorProxy)
+        .add_source(SOURCE_A_ADDRESS, 1u32, 60u64)
+        .with_result(ExpectError(4, "source already added"))
+        .run();
+
+    state
+        .world
+        .query()
+        .to(MULTI_SOURCE_AGGREGATOR_ADDRESS)
+        .typed(MultiSourceAggregatorProxy)
+        .get_sources()
+        .returns(ExpectValue(MultiValueVec::from(vec![MultiValue3::from((
+            SOURCE_A_ADDRESS.to_address(),
+            1u32,
+            60u64,
+        ))])))
+        .run();
+
+    state
+        .world
+        .tx()
+        .from(OWNER_ADDRESS)
+        .to(MULTI_SOURCE_AGGREGATOR_ADDRESS)
+        .typed(MultiSourceAggregatorProxy)
+        .remove_source(SOURCE_A_ADDRESS)
+        .run();
+    state
+        .world
+        .tx()
+        .from(OWNER_ADDRESS)
+        .to(MULTI_SOURCE_AGGREGATOR_ADDRESS)
+        .typed(MultiSourceAggregatorProxy)
+        .remove_source(SOURCE_A_ADDRESS)
+        .with_result(ExpectError(4, "source not found"))
+        .run();
+}
+
+#[test]
+fn test_multi_source_aggregator_update_price() {
+    let mut state = MultiSourceAggregatorTestState::new();
+    state.deploy(2);
+
+    // 100.00, 110.0000 and 130.00, the second source counting twice
+    state.deploy_source(SOURCE_A_ADDRESS, DECIMALS, 10_000);
+    state.deploy_source(SOURCE_B_ADDRESS, 4, 1_100_000);
+    state.deploy_source(SOURCE_C_ADDRESS, DECIMALS, 13_000);
+    state.add_source(SOURCE_A_ADDRESS, 1, 1_000);
+    state.add_source(SOURCE_B_ADDRESS, 2, 50);
+    state.add_source(SOURCE_C_ADDRESS, 1, 1_000);
+
+    state.update_price_and_expect_err("Contract is paused");
+    state
+        .world
+        .tx()
+        .from(OWNER_ADDRESS)
+        .to(MULTI_SOURCE_AGGREGATOR_ADDRESS)
+        .typed(MultiSourceAggregatorProxy)
+        .unpause_endpoint()
+        .run();
+    state.update_price_and_expect_err("pair decimals not configured");
+    state
+        .world
+        .tx()
+        .from(OWNER_ADDRESS)
+        .to(MULTI_SOURCE_AGGREGATOR_ADDRESS)
+        .typed(MultiSourceAggregatorProxy)
+        .set_pair_decimals(EGLD_TICKER, USD_TICKER, DECIMALS)
+        .run();
+
+    state.update_price();
+    state.check_latest_price_feed(1, 100, 11_000);
+
+    // no source has moved since the last update
+    state.world.current_block().block_timestamp(200);
+    state.update_price_and_expect_err("no new source price");
+
+    // the second source is now stale
+    state.refresh_source(SOURCE_A_ADDRESS, 10_000);
+    state.refresh_source(SOURCE_C_ADDRESS, 13_000);
+    state.update_price();
+    state.check_latest_price_feed(2, 200, 11_500);
+
+    // paused sources are skipped
+    state
+        .world
+        .tx()
+        .from(OWNER_ADDRESS)
+        .to(SOURCE_A_ADDRESS)
+        .typed(PriceAggregatorProxy)
+        .pause_endpoint()
+        .run();
+    state.update_price_and_expect_err("not enough fresh sources");
+
+    state
+        .world
+        .tx()
+        .from(OWNER_ADDRESS)
+        .to(MULTI_SOURCE_AGGREGATOR_ADDRESS)
+        .typed(MultiSourceAggregatorProxy)
+        .set_min_sources(1usize)
+        .run();
+    state.update_price_and_expect_err("no new source price");
+
+    state.world.current_block().block_timestamp(210);
+    state.refresh_source(SOURCE_C_ADDRESS, 13_000);
+    state.update_price();
+    state.check_latest_price_feed(3, 210, 13_000);
+}
@@ -0,0 +1,39 @@
+// Code generated by the multiversx-sc build system. DO NOT EDIT.
+
+////////////////////////////////////////////////////
+////////////////// AUTO-GENERATED //////////////////
+////////////////////////////////////////////////////
+
+// Init:                                 1
+// Upgrade:                              1
+// Endpoints:                           13
+// Async Callback (empty):               1
+// Total number of exported functions:  16
+
+#![no_std]
+
+multiversx_sc_wasm_adapter::allocator!();
+multiversx_sc_wasm_adapter::panic_handler!();
+
+multiversx_sc_wasm_adapter::endpoints! {
+    multi_source_aggregator
+    (
+        init => init
+        upgrade => upgrade
+        addSource => add_source
+        removeSource => remove_source
+        setMinSources => set_min_sources
+        setPairDecimals => set_pair_decimals
+        updatePrice => update_price
+        latestPriceFeed => latest_price_feed
+        getSources => get_sources
+        getMinSources => min_sources
+        getPairDecimals => pair_decimals
+        getLatestPrice => latest_price
+        pause => pause_endpoint
+        unpause => unpause_endpoint
+        isPaused => paused_status
+    )
+}
+
+multiversx_sc_wasm_adapter::async_callback_empty! {}
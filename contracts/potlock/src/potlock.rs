@@ -13,6 +13,7 @@ pub trait Potlock:
     + potlock_interactions::PotlockInteractions
     + potlock_storage::PotlockStorage
     + multiversx_sc_modules::only_admin::OnlyAdminModule
+    + price_consumer_module::PriceConsumerModule
 {
     #[init]
     fn init(&self, admins: MultiValueEncoded<ManagedAddress>) {
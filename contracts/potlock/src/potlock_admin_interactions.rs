@@ -8,7 +8,9 @@ pub const MAX_PERCENTAGE: u64 = 10_000; // 100%
 
 #[multiversx_sc::module]
 pub trait PotlockAdminInteractions:
-    potlock_storage::PotlockStorage + multiversx_sc_modules::only_admin::OnlyAdminModule
+    potlock_storage::PotlockStorage
+    + multiversx_sc_modules::only_admin::OnlyAdminModule
+    + price_consumer_module::PriceConsumerModule
 {
     #[only_admin]
     #[endpoint(changeFeeForPots)]
@@ -25,6 +27,12 @@ pub trait PotlockAdminInteractions:
         self.fee_amount().set(fee);
     }
 
+    #[only_admin]
+    #[endpoint(setPriceAggregator)]
+    fn set_price_aggregator_endpoint(&self, address: ManagedAddress, max_age_seconds: u64) {
+        self.set_price_aggregator(address, max_age_seconds);
+    }
+
     #[only_admin]
     #[endpoint(acceptPot)]
     fn accept_pot(&self, potlock_id: PotlockId) {
@@ -1,10 +1,15 @@
+use multiversx_price_aggregator_sc::price_aggregator_proxy::PriceAggregatorProxy;
 use multiversx_sc_scenario::{imports::*, ScenarioWorld};
 use potlock::potlock_storage::{PotlockId, ProjectId};
 use potlock_proxy::Status;
+use price_consumer_module::PriceConsumerModule;
 mod potlock_proxy;
 
 const POTLOCK_ADDRESS: TestSCAddress = TestSCAddress::new("potlock");
 const POTLOCK_CODE_PATH: MxscPath = MxscPath::new("output/potlock.mxsc.json");
+const PRICE_AGGREGATOR_ADDRESS: TestSCAddress = TestSCAddress::new("price-aggregator");
+const PRICE_AGGREGATOR_CODE_PATH: MxscPath =
+    MxscPath::new("../price-aggregator/output/multiversx-price-aggregator-sc.mxsc.json");
 const OWNER_ADDRESS: TestAddress = TestAddress::new("owner");
 const ADMIN_ADDRESS: TestAddress = TestAddress::new("admin");
 const POT_PROPOSER_ADDRESS: TestAddress = TestAddress::new("pot_proposer");
@@ -23,6 +28,10 @@ fn world() -> ScenarioWorld {
     let mut blockchain = ScenarioWorld::new();
 
     blockchain.register_contract(POTLOCK_CODE_PATH, potlock::ContractBuilder);
+    blockchain.register_contract(
+        PRICE_AGGREGATOR_CODE_PATH,
+        multiversx_price_aggregator_sc::ContractBuilder,
+    );
     blockchain
 }
 
@@ -642,3 +651,114 @@ fn test_fail_donate_to_project() {
         ))
         .run();
 }
+
+#[test]
+fn test_price_consumer() {
+    let mut state = PotlockTestState::new();
+    state.deploy_potlock_contract();
+    state.world.current_block().block_timestamp(100);
+
+    // an aggregator serving 1 EGLD = 25.00 USDC
+    let oracles = MultiValueVec::from(vec![
+        TestAddress::new("oracle1").to_address(),
+        TestAddress::new("oracle2").to_address(),
+        TestAddress::new("oracle3").to_address(),
+    ]);
+    state
+        .world
+        .tx()
+        .from(OWNER_ADDRESS)
+        .typed(PriceAggregatorProxy)
+        .init(
+            EgldOrEsdtTokenIdentifier::egld(),
+            1u64,
+            1u64,
+            3usize,
+            3usize,
+            oracles,
+        )
+        .code(PRICE_AGGREGATOR_CODE_PATH)
+        .new_address(PRICE_AGGREGATOR_ADDRESS)
+        .run();
+    state
+        .world
+        .tx()
+        .from(OWNER_ADDRESS)
+        .to(PRICE_AGGREGATOR_ADDRESS)
+        .typed(PriceAggregatorProxy)
+        .set_pair_decimals("EGLD", "USDC", 2u8)
+        .run();
+    state
+        .world
+        .tx()
+        .from(OWNER_ADDRESS)
+        .to(PRICE_AGGREGATOR_ADDRESS)
+        .typed(PriceAggregatorProxy)
+        .set_manual_price("EGLD", "USDC", 2_500u64, 1_000u64)
+        .run();
+    state
+        .world
+        .tx()
+        .from(OWNER_ADDRESS)
+        .to(PRICE_AGGREGATOR_ADDRESS)
+        .typed(PriceAggregatorProxy)
+        .unpause_endpoint()
+        .run();
+
+    state
+        .world
+        .tx()
+        .from(POT_DONOR_ADDRESS)
+        .to(POTLOCK_ADDRESS)
+        .typed(potlock_proxy::PotlockProxy)
+        .set_price_aggregator_endpoint(PRICE_AGGREGATOR_ADDRESS, 60u64)
+        .with_result(ExpectError(4, "Endpoint can only be called by admins"))
+        .run();
+    state
+        .world
+        .tx()
+        .from(ADMIN_ADDRESS)
+        .to(POTLOCK_ADDRESS)
+        .typed(potlock_proxy::PotlockProxy)
+        .set_price_aggregator_endpoint(PRICE_AGGREGATOR_ADDRESS, 60u64)
+        .run();
+
+    let potlock_whitebox = WhiteboxContract::new(POTLOCK_ADDRESS, potlock::contract_obj);
+    state.world.whitebox_query(&potlock_whitebox, |sc| {
+        let price_feed = sc.get_price_feed(&managed_buffer!(b"EGLD"), &managed_buffer!(b"USDC"));
+        assert_eq!(price_feed.price, managed_biguint!(2_500));
+        assert_eq!(price_feed.decimals, 2);
+
+        // 2 EGLD with 18 decimals are 50 USDC with 6 decimals, and back
+        let usdc_amount = sc.convert_amount(
+            &(managed_biguint!(2) * BigUint::from(10u64).pow(18)),
+            &managed_buffer!(b"EGLD"),
+            18,
+            &managed_buffer!(b"USDC"),
+            6,
+        );
+        assert_eq!(usdc_amount, managed_biguint!(50_000_000));
+
+        let egld_amount = sc.convert_amount_by_inverse_pair(
+            &usdc_amount,
+            &managed_buffer!(b"USDC"),
+            6,
+            &managed_buffer!(b"EGLD"),
+            18,
+        );
+        assert_eq!(
+            egld_amount,
+            managed_biguint!(2) * BigUint::from(10u64).pow(18)
+        );
+    });
+
+    state.world.current_block().block_timestamp(200);
+    state.world.whitebox_call_check(
+        &potlock_whitebox,
+        ScCallStep::new().from(OWNER_ADDRESS).no_expect(),
+        |sc| {
+            let _ = sc.get_price_feed(&managed_buffer!(b"EGLD"), &managed_buffer!(b"USDC"));
+        },
+        |tx_result| tx_result.assert_user_error("price feed is stale"),
+    );
+}
@@ -85,6 +85,22 @@ where
     To: TxTo<Env>,
     Gas: TxGas<Env>,
 {
+    pub fn set_price_aggregator_endpoint<
+        Arg0: ProxyArg<ManagedAddress<Env::Api>>,
+        Arg1: ProxyArg<u64>,
+    >(
+        self,
+        address: Arg0,
+        max_age_seconds: Arg1,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("setPriceAggregator")
+            .argument(&address)
+            .argument(&max_age_seconds)
+            .original_result()
+    }
+
     pub fn accept_pot<
         Arg0: ProxyArg<usize>,
     >(
@@ -361,6 +377,24 @@ where
             .raw_call("getAdmins")
             .original_result()
     }
+
+    pub fn price_aggregator_address(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ManagedAddress<Env::Api>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getPriceAggregatorAddress")
+            .original_result()
+    }
+
+    pub fn price_feed_max_age_seconds(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, u64> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getPriceFeedMaxAgeSeconds")
+            .original_result()
+    }
 }
 
 #[type_abi]
@@ -6,9 +6,9 @@
 
 // Init:                                 1
 // Upgrade:                              1
-// Endpoints:                           21
+// Endpoints:                           24
 // Async Callback (empty):               1
-// Total number of exported functions:  24
+// Total number of exported functions:  27
 
 #![no_std]
 
@@ -21,6 +21,7 @@ multiversx_sc_wasm_adapter::endpoints! {
         init => init
         upgrade => upgrade
         changeFeeForPots => change_fee_for_pots
+        setPriceAggregator => set_price_aggregator_endpoint
         acceptPot => accept_pot
         removePot => remove_pot
         acceptApplication => accept_application
@@ -41,6 +42,8 @@ multiversx_sc_wasm_adapter::endpoints! {
         addAdmin => add_admin
         removeAdmin => remove_admin
         getAdmins => admins
+        getPriceAggregatorAddress => price_aggregator_address
+        getPriceFeedMaxAgeSeconds => price_feed_max_age_seconds
     )
 }
 
@@ -381,7 +381,7 @@ impl ContractInteract {
             .to(self.state.current_address())
             .gas(30_000_000u64)
             .typed(proxy::PriceAggregatorProxy)
-            .submit(from, to, submission_timestamp, price, decimals)
+            .submit(from, to, submission_timestamp, price, decimals, OptionalValue::<BigUint<StaticApi>>::None)
             .returns(ReturnsResultUnmanaged)
             .prepare_async()
             .run()
@@ -411,7 +411,7 @@ impl ContractInteract {
             .to(self.state.current_address())
             .gas(30_000_000u64)
             .typed(proxy::PriceAggregatorProxy)
-            .submit(from, to, submission_timestamp, price, decimals)
+            .submit(from, to, submission_timestamp, price, decimals, OptionalValue::<BigUint<StaticApi>>::None)
             .returns(ReturnsResultUnmanaged)
             .prepare_async()
             .run()
@@ -438,7 +438,7 @@ impl ContractInteract {
             .to(self.state.current_address())
             .gas(30_000_000u64)
             .typed(proxy::PriceAggregatorProxy)
-            .submit(from, to, submission_timestamp, price, decimals)
+            .submit(from, to, submission_timestamp, price, decimals, OptionalValue::<BigUint<StaticApi>>::None)
             .returns(expected_result)
             .prepare_async()
             .run()
@@ -761,7 +761,7 @@ impl ContractInteract {
             .to(self.state.current_address())
             .gas(30_000_000u64)
             .typed(proxy::PriceAggregatorProxy)
-            .unstake(unstake_amount)
+            .unstake(unstake_amount, OptionalValue::<EgldOrEsdtTokenIdentifier<StaticApi>>::None)
             .returns(ReturnsResultUnmanaged)
             .prepare_async()
             .run()
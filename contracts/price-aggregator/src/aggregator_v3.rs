@@ -0,0 +1,113 @@
+use multiversx_sc::imports::*;
+
+use crate::{
+    events, manual_price,
+    price_aggregator_data::{TimestampedPrice, TokenPair},
+    roles, storage, PAIR_DECIMALS_NOT_CONFIGURED_ERROR, PAUSED_ERROR_MSG,
+};
+
+pub const AGGREGATOR_V3_VERSION: u64 = 1;
+const ROUND_NOT_FOUND_ERROR: &[u8] = b"round not found";
+
+/// The views of Chainlink's AggregatorV3Interface, for a single pair.
+/// Rounds only record the time they were created, which is returned as both `startedAt` and `updatedAt`.
+#[multiversx_sc::module]
+pub trait AggregatorV3Module:
+    storage::StorageModule
+    + manual_price::ManualPriceModule
+    + roles::RolesModule
+    + multiversx_sc_modules::pause::PauseModule
+    + events::EventsModule
+{
+    /// Returns `(roundId, answer, startedAt, updatedAt, answeredInRound)`.
+    /// An active manual price is returned with the id of the last round.
+    #[view(latestRoundDataV3)]
+    fn latest_round_data_v3(
+        &self,
+        from: ManagedBuffer,
+        to: ManagedBuffer,
+    ) -> MultiValue5<u32, BigUint, u64, u64, u32> {
+        require!(self.not_paused(), PAUSED_ERROR_MSG);
+
+        let token_pair = TokenPair { from, to };
+        let round_values_opt = self.rounds().get(&token_pair);
+        let round_id = round_values_opt
+            .as_ref()
+            .map_or(0, |round_values| round_values.len());
+
+        if let Some(manual_price) = self.get_active_manual_price(&token_pair) {
+            return self.make_round_data(
+                round_id,
+                TimestampedPrice {
+                    price: manual_price.price,
+                    timestamp: manual_price.timestamp,
+                    decimals: manual_price.decimals,
+                },
+            );
+        }
+
+        let round_values = round_values_opt.unwrap_or_else(|| sc_panic!(ROUND_NOT_FOUND_ERROR));
+        self.make_round_data(round_id, round_values.get(round_id))
+    }
+
+    /// Returns `(roundId, answer, startedAt, updatedAt, answeredInRound)` for a past round.
+    #[view(getRoundData)]
+    fn get_round_data(
+        &self,
+        from: ManagedBuffer,
+        to: ManagedBuffer,
+        round_id: u32,
+    ) -> MultiValue5<u32, BigUint, u64, u64, u32> {
+        require!(self.not_paused(), PAUSED_ERROR_MSG);
+
+        let round_values = self
+            .rounds()
+            .get(&TokenPair { from, to })
+            .unwrap_or_else(|| sc_panic!(ROUND_NOT_FOUND_ERROR));
+        let round_id = round_id as usize;
+        require!(
+            round_id > 0 && round_id <= round_values.len(),
+            ROUND_NOT_FOUND_ERROR
+        );
+
+        self.make_round_data(round_id, round_values.get(round_id))
+    }
+
+    #[view(decimals)]
+    fn decimals(&self, from: ManagedBuffer, to: ManagedBuffer) -> u8 {
+        self.pair_decimals(&from, &to)
+            .get()
+            .unwrap_or_else(|| sc_panic!(PAIR_DECIMALS_NOT_CONFIGURED_ERROR))
+    }
+
+    /// Formatted as `FROM / TO`, like the Chainlink feeds.
+    #[view(description)]
+    fn description(&self, from: ManagedBuffer, to: ManagedBuffer) -> ManagedBuffer {
+        let mut description = from;
+        description.append_bytes(b" / ");
+        description.append(&to);
+
+        description
+    }
+
+    #[view(version)]
+    fn version(&self) -> u64 {
+        AGGREGATOR_V3_VERSION
+    }
+
+    fn make_round_data(
+        &self,
+        round_id: usize,
+        price_feed: TimestampedPrice<Self::Api>,
+    ) -> MultiValue5<u32, BigUint, u64, u64, u32> {
+        let round_id = round_id as u32;
+        (
+            round_id,
+            price_feed.price,
+            price_feed.timestamp,
+            price_feed.timestamp,
+            round_id,
+        )
+            .into()
+    }
+}
@@ -0,0 +1,137 @@
+use multiversx_sc::imports::*;
+
+use crate::{
+    events, manual_price,
+    price_aggregator_data::{Basket, BasketConstituent, BasketPrice, TokenPair},
+    roles, storage, PAUSED_ERROR_MSG,
+};
+
+pub const BASKET_WEIGHT_DECIMALS: u32 = 18;
+pub const MAX_BASKET_CONSTITUENTS: usize = 20;
+const BASKET_NOT_FOUND_ERROR: &[u8] = b"basket not found";
+
+#[multiversx_sc::module]
+pub trait BasketModule:
+    manual_price::ManualPriceModule
+    + storage::StorageModule
+    + roles::RolesModule
+    + multiversx_sc_modules::pause::PauseModule
+    + events::EventsModule
+{
+    /// Defines a composite feed as the weighted sum of existing pairs.
+    /// Weights have `BASKET_WEIGHT_DECIMALS` decimals, e.g. 10^18 for one unit of the constituent.
+    #[only_owner]
+    #[endpoint(createBasket)]
+    fn create_basket(
+        &self,
+        name: ManagedBuffer,
+        decimals: u8,
+        constituents: MultiValueEncoded<MultiValue3<ManagedBuffer, ManagedBuffer, BigUint>>,
+    ) {
+        require!(!name.is_empty(), "invalid basket name");
+        require!(!self.baskets().contains_key(&name), "basket already exists");
+        require!(
+            !constituents.is_empty() && constituents.len() <= MAX_BASKET_CONSTITUENTS,
+            "invalid number of constituents"
+        );
+
+        let mut basket = Basket {
+            decimals,
+            constituents: ManagedVec::new(),
+        };
+        for constituent in constituents {
+            let (from, to, weight) = constituent.into_tuple();
+            require!(weight > 0u64, "invalid constituent weight");
+
+            let token_pair = TokenPair { from, to };
+            require!(
+                self.rounds().contains_key(&token_pair),
+                "constituent pair has no rounds"
+            );
+            basket.constituents.push(BasketConstituent {
+                from: token_pair.from,
+                to: token_pair.to,
+                weight,
+            });
+        }
+
+        let _ = self.baskets().insert(name, basket);
+    }
+
+    #[only_owner]
+    #[endpoint(removeBasket)]
+    fn remove_basket(&self, name: ManagedBuffer) {
+        require!(
+            self.baskets().remove(&name).is_some(),
+            BASKET_NOT_FOUND_ERROR
+        );
+    }
+
+    /// Computed from the latest price feed of every constituent, which is its manual price while one is active.
+    /// The timestamp is the one of the oldest constituent price.
+    #[view(getBasketPrice)]
+    fn get_basket_price(&self, name: ManagedBuffer) -> BasketPrice<Self::Api> {
+        require!(self.not_paused(), PAUSED_ERROR_MSG);
+
+        let basket = self.get_basket(name);
+        let mut price = BigUint::zero();
+        let mut timestamp = u64::MAX;
+        for constituent in basket.constituents.iter() {
+            let token_pair = TokenPair {
+                from: constituent.from.clone(),
+                to: constituent.to.clone(),
+            };
+            let (last_price, last_decimals, last_timestamp) =
+                match self.get_active_manual_price(&token_pair) {
+                    Some(manual_price) => (
+                        manual_price.price,
+                        manual_price.decimals,
+                        manual_price.timestamp,
+                    ),
+                    None => {
+                        let round_values = self
+                            .rounds()
+                            .get(&token_pair)
+                            .unwrap_or_else(|| sc_panic!("constituent pair has no rounds"));
+                        let last_round = round_values.get(round_values.len());
+                        (last_round.price, last_round.decimals, last_round.timestamp)
+                    }
+                };
+
+            let normalized_price =
+                self.convert_decimals(&last_price, last_decimals, basket.decimals);
+            price += normalized_price * &constituent.weight;
+            timestamp = timestamp.min(last_timestamp);
+        }
+
+        BasketPrice {
+            price: price / BigUint::from(10u64).pow(BASKET_WEIGHT_DECIMALS),
+            decimals: basket.decimals,
+            timestamp,
+        }
+    }
+
+    fn convert_decimals(&self, price: &BigUint, from_decimals: u8, to_decimals: u8) -> BigUint {
+        let ten = BigUint::from(10u64);
+        if to_decimals >= from_decimals {
+            price * &ten.pow((to_decimals - from_decimals) as u32)
+        } else {
+            price / &ten.pow((from_decimals - to_decimals) as u32)
+        }
+    }
+
+    #[view(getBasket)]
+    fn get_basket(&self, name: ManagedBuffer) -> Basket<Self::Api> {
+        self.baskets()
+            .get(&name)
+            .unwrap_or_else(|| sc_panic!(BASKET_NOT_FOUND_ERROR))
+    }
+
+    #[view(getBasketNames)]
+    fn get_basket_names(&self) -> MultiValueEncoded<ManagedBuffer> {
+        self.baskets().keys().collect()
+    }
+
+    #[storage_mapper("baskets")]
+    fn baskets(&self) -> MapMapper<ManagedBuffer, Basket<Self::Api>>;
+}
@@ -0,0 +1,160 @@
+use multiversx_sc::imports::*;
+
+use crate::{
+    events,
+    price_aggregator_data::{AdminAction, AdminActionProposal},
+    staking,
+};
+
+const PROPOSAL_NOT_FOUND_ERROR: &[u8] = b"proposal not found";
+const PROPOSAL_EXPIRED_ERROR: &[u8] = b"proposal expired";
+
+#[multiversx_sc::module]
+pub trait BoardGovernanceModule: staking::StakingModule + events::EventsModule {
+    #[only_owner]
+    #[endpoint(setGovernanceConfig)]
+    fn set_governance_config(&self, quorum: usize, voting_period_seconds: u64) {
+        require!(
+            quorum > 0 && voting_period_seconds > 0,
+            "Quorum and voting period cannot be 0"
+        );
+
+        self.governance_quorum().set(quorum);
+        self.voting_period_seconds().set(voting_period_seconds);
+    }
+
+    /// Staked board members propose an administrative action, which counts as their vote as well.
+    #[endpoint(proposeAdminAction)]
+    fn propose_admin_action(&self, action: AdminAction<Self::Api>) -> u64 {
+        let caller = self.require_staked_board_member();
+        require!(
+            matches!(
+                action,
+                AdminAction::AddOracles { .. }
+                    | AdminAction::RemoveOracles { .. }
+                    | AdminAction::SetSubmissionCount { .. }
+                    | AdminAction::ChangeAmounts { .. }
+            ),
+            "action not allowed for the board"
+        );
+        let voting_period_seconds = self.voting_period_seconds().get();
+        require!(voting_period_seconds > 0, "governance not configured");
+
+        let proposal_id = self.last_proposal_id().update(|id| {
+            *id += 1;
+            *id
+        });
+        let proposal = AdminActionProposal {
+            action,
+            proposer: caller.clone(),
+            deadline: self.blockchain().get_block_timestamp() + voting_period_seconds,
+        };
+        self.admin_action_proposed_event(proposal_id, &proposal);
+        self.proposals(proposal_id).set(proposal);
+        let _ = self.open_proposal_ids().insert(proposal_id);
+        let _ = self.proposal_voters(proposal_id).insert(caller);
+
+        proposal_id
+    }
+
+    #[endpoint(voteAdminAction)]
+    fn vote_admin_action(&self, proposal_id: u64) {
+        let caller = self.require_staked_board_member();
+        self.require_proposal_open(proposal_id);
+
+        let _ = self.proposal_voters(proposal_id).insert(caller);
+    }
+
+    #[endpoint(cancelVoteAdminAction)]
+    fn cancel_vote_admin_action(&self, proposal_id: u64) {
+        let caller = self.blockchain().get_caller();
+        let _ = self.proposal_voters(proposal_id).swap_remove(&caller);
+    }
+
+    /// Removes the proposal and returns its action,
+    /// if enough of the current staked board members voted for it before the deadline.
+    fn take_approved_proposal(&self, proposal_id: u64) -> AdminAction<Self::Api> {
+        self.require_proposal_open(proposal_id);
+        require!(
+            self.get_proposal_vote_count(proposal_id) >= self.governance_quorum().get(),
+            "Quorum not reached"
+        );
+
+        self.proposal_voters(proposal_id).clear();
+        let _ = self.open_proposal_ids().swap_remove(&proposal_id);
+        self.proposals(proposal_id).take().action
+    }
+
+    /// Moves the votes on open proposals from one address to another.
+    /// Expired proposals can no longer be executed, so they are dropped along the way.
+    fn move_proposal_votes(&self, old_voter: &ManagedAddress, new_voter: &ManagedAddress) {
+        let current_timestamp = self.blockchain().get_block_timestamp();
+        let mut expired_proposal_ids = ManagedVec::<Self::Api, u64>::new();
+        for proposal_id in self.open_proposal_ids().iter() {
+            let proposal_mapper = self.proposals(proposal_id);
+            if proposal_mapper.is_empty() || current_timestamp > proposal_mapper.get().deadline {
+                expired_proposal_ids.push(proposal_id);
+                continue;
+            }
+
+            let mut voters_mapper = self.proposal_voters(proposal_id);
+            if voters_mapper.swap_remove(old_voter) {
+                let _ = voters_mapper.insert(new_voter.clone());
+            }
+        }
+
+        let mut open_proposal_ids_mapper = self.open_proposal_ids();
+        for proposal_id in expired_proposal_ids.iter() {
+            let _ = open_proposal_ids_mapper.swap_remove(&proposal_id);
+        }
+    }
+
+    fn require_staked_board_member(&self) -> ManagedAddress {
+        let caller = self.blockchain().get_caller();
+        require!(
+            self.is_staked_board_member(&caller),
+            "only board members allowed"
+        );
+
+        caller
+    }
+
+    fn require_proposal_open(&self, proposal_id: u64) {
+        let proposal_mapper = self.proposals(proposal_id);
+        require!(!proposal_mapper.is_empty(), PROPOSAL_NOT_FOUND_ERROR);
+        require!(
+            self.blockchain().get_block_timestamp() <= proposal_mapper.get().deadline,
+            PROPOSAL_EXPIRED_ERROR
+        );
+    }
+
+    /// Only votes from members that are still staked board members are counted.
+    #[view(getProposalVoteCount)]
+    fn get_proposal_vote_count(&self, proposal_id: u64) -> usize {
+        self.proposal_voters(proposal_id)
+            .iter()
+            .filter(|voter| self.is_staked_board_member(voter))
+            .count()
+    }
+
+    #[view(getGovernanceQuorum)]
+    #[storage_mapper("governance_quorum")]
+    fn governance_quorum(&self) -> SingleValueMapper<usize>;
+
+    #[view(getVotingPeriodSeconds)]
+    #[storage_mapper("voting_period_seconds")]
+    fn voting_period_seconds(&self) -> SingleValueMapper<u64>;
+
+    #[storage_mapper("last_proposal_id")]
+    fn last_proposal_id(&self) -> SingleValueMapper<u64>;
+
+    #[view(getProposal)]
+    #[storage_mapper("proposals")]
+    fn proposals(&self, proposal_id: u64) -> SingleValueMapper<AdminActionProposal<Self::Api>>;
+
+    #[storage_mapper("open_proposal_ids")]
+    fn open_proposal_ids(&self) -> UnorderedSetMapper<u64>;
+
+    #[storage_mapper("proposal_voters")]
+    fn proposal_voters(&self, proposal_id: u64) -> UnorderedSetMapper<ManagedAddress>;
+}
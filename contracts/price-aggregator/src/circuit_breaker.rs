@@ -0,0 +1,164 @@
+use multiversx_sc::imports::*;
+
+use crate::{
+    events,
+    price_aggregator_data::{CircuitBreakerConfig, Role, TimestampedPrice, TokenPair},
+    roles, statistics, storage, subscriptions,
+};
+
+pub const DEVIATION_PRECISION: u64 = 10_000; // basis points
+
+#[multiversx_sc::module]
+pub trait CircuitBreakerModule:
+    statistics::StatisticsModule
+    + subscriptions::SubscriptionsModule
+    + storage::StorageModule
+    + roles::RolesModule
+    + multiversx_sc_modules::pause::PauseModule
+    + events::EventsModule
+{
+    /// Rounds moving more than `max_deviation_bps` from the previous round are held
+    /// until a round within `max_deviation_bps` of the held price confirms them
+    /// during `confirmation_window_seconds`, or until the owner releases them.
+    /// A deviation of 0 disables the circuit breaker.
+    #[endpoint(setCircuitBreaker)]
+    fn set_circuit_breaker(&self, max_deviation_bps: u64, confirmation_window_seconds: u64) {
+        self.require_role(Role::PairManager);
+        require!(
+            max_deviation_bps <= DEVIATION_PRECISION,
+            "invalid max deviation"
+        );
+
+        self.circuit_breaker_config().set(CircuitBreakerConfig {
+            max_deviation_bps,
+            confirmation_window_seconds,
+        });
+    }
+
+    /// Publishes the held price of the pair as a new round.
+    #[only_owner]
+    #[endpoint(releaseHeldPrice)]
+    fn release_held_price(&self, from: ManagedBuffer, to: ManagedBuffer) {
+        let token_pair = TokenPair { from, to };
+        let held_price_mapper = self.held_price(&token_pair);
+        require!(!held_price_mapper.is_empty(), "no held price");
+
+        let held_price = held_price_mapper.take();
+        self.held_price_released_event(&token_pair.from, &token_pair.to, &held_price);
+        self.push_round(&token_pair, held_price);
+    }
+
+    /// Publishes the round, unless it trips the circuit breaker.
+    fn publish_round(
+        &self,
+        token_pair: &TokenPair<Self::Api>,
+        price_feed: TimestampedPrice<Self::Api>,
+    ) {
+        let config = self.circuit_breaker_config();
+        if config.is_empty() {
+            self.push_round(token_pair, price_feed);
+            return;
+        }
+
+        let config = config.get();
+        if config.max_deviation_bps == 0 {
+            self.push_round(token_pair, price_feed);
+            return;
+        }
+
+        let rounds = match self.rounds().get(token_pair) {
+            Some(rounds) if !rounds.is_empty() => rounds,
+            _ => {
+                self.push_round(token_pair, price_feed);
+                return;
+            }
+        };
+        let last_price = rounds.get(rounds.len());
+        if !self.exceeds_deviation(&last_price, &price_feed, config.max_deviation_bps) {
+            self.held_price(token_pair).clear();
+            self.push_round(token_pair, price_feed);
+            return;
+        }
+
+        let held_price_mapper = self.held_price(token_pair);
+        if !held_price_mapper.is_empty() {
+            let held_price = held_price_mapper.get();
+            let confirmation_deadline = held_price.timestamp + config.confirmation_window_seconds;
+            if price_feed.timestamp <= confirmation_deadline
+                && !self.exceeds_deviation(&held_price, &price_feed, config.max_deviation_bps)
+            {
+                held_price_mapper.clear();
+                self.push_round(token_pair, price_feed);
+                return;
+            }
+        }
+
+        self.price_held_event(&token_pair.from, &token_pair.to, &price_feed);
+        held_price_mapper.set(price_feed);
+    }
+
+    fn push_round(
+        &self,
+        token_pair: &TokenPair<Self::Api>,
+        price_feed: TimestampedPrice<Self::Api>,
+    ) {
+        self.manual_price(token_pair).clear();
+        self.update_price_statistics(token_pair, &price_feed);
+        self.rounds()
+            .entry(token_pair.clone())
+            .or_default()
+            .get()
+            .push(&price_feed);
+        self.emit_new_round_event(token_pair, &price_feed);
+
+        let round_id = self
+            .rounds()
+            .get(token_pair)
+            .map_or(0, |rounds| rounds.len());
+        self.notify_subscribers(token_pair, round_id, &price_feed);
+    }
+
+    /// Prices with a different number of decimals cannot be compared and never trip the breaker.
+    fn exceeds_deviation(
+        &self,
+        reference: &TimestampedPrice<Self::Api>,
+        price_feed: &TimestampedPrice<Self::Api>,
+        max_deviation_bps: u64,
+    ) -> bool {
+        if reference.decimals != price_feed.decimals || reference.price == 0u64 {
+            return false;
+        }
+
+        let difference = if price_feed.price > reference.price {
+            &price_feed.price - &reference.price
+        } else {
+            &reference.price - &price_feed.price
+        };
+
+        difference * DEVIATION_PRECISION > &reference.price * max_deviation_bps
+    }
+
+    #[view(getHeldPrice)]
+    fn get_held_price(
+        &self,
+        from: ManagedBuffer,
+        to: ManagedBuffer,
+    ) -> OptionalValue<TimestampedPrice<Self::Api>> {
+        let held_price_mapper = self.held_price(&TokenPair { from, to });
+        if held_price_mapper.is_empty() {
+            OptionalValue::None
+        } else {
+            OptionalValue::Some(held_price_mapper.get())
+        }
+    }
+
+    #[view(getCircuitBreakerConfig)]
+    #[storage_mapper("circuit_breaker_config")]
+    fn circuit_breaker_config(&self) -> SingleValueMapper<CircuitBreakerConfig>;
+
+    #[storage_mapper("held_price")]
+    fn held_price(
+        &self,
+        token_pair: &TokenPair<Self::Api>,
+    ) -> SingleValueMapper<TimestampedPrice<Self::Api>>;
+}
@@ -0,0 +1,168 @@
+use multiversx_sc::imports::*;
+
+use crate::{
+    circuit_breaker::DEVIATION_PRECISION,
+    events, median,
+    price_aggregator_data::{Role, RoundConfidence, TokenPair},
+    roles, storage, SUBMISSION_LIST_MAX_LEN,
+};
+
+#[multiversx_sc::module]
+pub trait ConfidenceModule:
+    storage::StorageModule
+    + roles::RolesModule
+    + multiversx_sc_modules::pause::PauseModule
+    + events::EventsModule
+{
+    /// Submissions with a confidence interval wider than `max_confidence_bps` of their price
+    /// are left out of the median, unless every submission of the round is.
+    #[endpoint(setPairMaxConfidence)]
+    fn set_pair_max_confidence(
+        &self,
+        from: ManagedBuffer,
+        to: ManagedBuffer,
+        max_confidence_bps: u64,
+    ) {
+        self.require_role(Role::PairManager);
+        require!(
+            max_confidence_bps > 0 && max_confidence_bps <= DEVIATION_PRECISION,
+            "invalid max confidence"
+        );
+
+        self.pair_max_confidence_bps(&TokenPair { from, to })
+            .set(max_confidence_bps);
+    }
+
+    #[endpoint(clearPairMaxConfidence)]
+    fn clear_pair_max_confidence(&self, from: ManagedBuffer, to: ManagedBuffer) {
+        self.require_role(Role::PairManager);
+
+        self.pair_max_confidence_bps(&TokenPair { from, to })
+            .clear();
+    }
+
+    fn record_submission_confidence(
+        &self,
+        token_pair: &TokenPair<Self::Api>,
+        oracle: &ManagedAddress,
+        confidence: Option<BigUint>,
+    ) {
+        let mut confidence_mapper = self.submission_confidence(token_pair);
+        match confidence {
+            Some(confidence) => {
+                let _ = confidence_mapper.insert(oracle.clone(), confidence);
+            }
+            None => {
+                let _ = confidence_mapper.remove(oracle);
+            }
+        }
+    }
+
+    /// Returns the prices used for the median of the round, and stores the median of their confidence intervals.
+    fn take_round_prices(
+        &self,
+        token_pair: &TokenPair<Self::Api>,
+        submissions: &MapMapper<ManagedAddress, BigUint>,
+        round_timestamp: u64,
+    ) -> ArrayVec<BigUint, SUBMISSION_LIST_MAX_LEN> {
+        let (prices, mut confidences) = self.select_round_prices(token_pair, submissions);
+
+        let sample_count = confidences.len();
+        let round_confidence_mapper = self.round_confidence(token_pair);
+        match median::calculate(confidences.as_mut_slice())
+            .unwrap_or_else(|err| sc_panic!(err.as_bytes()))
+        {
+            Some(confidence) => round_confidence_mapper.set(RoundConfidence {
+                timestamp: round_timestamp,
+                confidence,
+                sample_count,
+            }),
+            None => round_confidence_mapper.clear(),
+        }
+        self.submission_confidence(token_pair).clear();
+
+        prices
+    }
+
+    /// Leaves out the submissions wider than the pair's maximum confidence,
+    /// unless every submission is. Returns the remaining prices and their confidence intervals.
+    fn select_round_prices(
+        &self,
+        token_pair: &TokenPair<Self::Api>,
+        submissions: &MapMapper<ManagedAddress, BigUint>,
+    ) -> (
+        ArrayVec<BigUint, SUBMISSION_LIST_MAX_LEN>,
+        ArrayVec<BigUint, SUBMISSION_LIST_MAX_LEN>,
+    ) {
+        let confidence_mapper = self.submission_confidence(token_pair);
+        let max_confidence_mapper = self.pair_max_confidence_bps(token_pair);
+        let max_confidence_bps = if max_confidence_mapper.is_empty() {
+            None
+        } else {
+            Some(max_confidence_mapper.get())
+        };
+
+        let mut prices = ArrayVec::<BigUint, SUBMISSION_LIST_MAX_LEN>::new();
+        let mut confidences = ArrayVec::<BigUint, SUBMISSION_LIST_MAX_LEN>::new();
+        for (oracle, price) in submissions.iter() {
+            let confidence = confidence_mapper.get(&oracle);
+            let within_max_confidence = match (&confidence, max_confidence_bps) {
+                (Some(confidence), Some(max_confidence_bps)) => {
+                    confidence * DEVIATION_PRECISION <= &price * max_confidence_bps
+                }
+                _ => true,
+            };
+            if !within_max_confidence {
+                continue;
+            }
+
+            if let Some(confidence) = confidence {
+                confidences.push(confidence);
+            }
+            prices.push(price);
+        }
+
+        if prices.is_empty() {
+            confidences.clear();
+            for (oracle, price) in submissions.iter() {
+                if let Some(confidence) = confidence_mapper.get(&oracle) {
+                    confidences.push(confidence);
+                }
+                prices.push(price);
+            }
+        }
+
+        (prices, confidences)
+    }
+
+    /// The median of the confidence intervals submitted for the last round of the pair,
+    /// if any oracle attached one.
+    #[view(getRoundConfidence)]
+    fn get_round_confidence(
+        &self,
+        from: ManagedBuffer,
+        to: ManagedBuffer,
+    ) -> OptionalValue<RoundConfidence<Self::Api>> {
+        let round_confidence_mapper = self.round_confidence(&TokenPair { from, to });
+        if round_confidence_mapper.is_empty() {
+            OptionalValue::None
+        } else {
+            OptionalValue::Some(round_confidence_mapper.get())
+        }
+    }
+
+    /// Zero when submissions are not filtered by confidence.
+    #[view(getPairMaxConfidence)]
+    fn get_pair_max_confidence(&self, from: ManagedBuffer, to: ManagedBuffer) -> u64 {
+        self.pair_max_confidence_bps(&TokenPair { from, to }).get()
+    }
+
+    #[storage_mapper("pair_max_confidence_bps")]
+    fn pair_max_confidence_bps(&self, token_pair: &TokenPair<Self::Api>) -> SingleValueMapper<u64>;
+
+    #[storage_mapper("round_confidence")]
+    fn round_confidence(
+        &self,
+        token_pair: &TokenPair<Self::Api>,
+    ) -> SingleValueMapper<RoundConfidence<Self::Api>>;
+}
@@ -1,7 +1,10 @@
 use multiversx_sc::derive_imports::*;
 use multiversx_sc::imports::*;
 
-use crate::price_aggregator_data::{TimestampedPrice, TokenPair};
+use crate::price_aggregator_data::{
+    AdminAction, AdminActionProposal, ManualPrice, OracleApplication, Role, TimestampedPrice,
+    TokenPair,
+};
 
 #[type_abi]
 #[derive(TopEncode)]
@@ -43,4 +46,127 @@ pub trait EventsModule {
         #[indexed] epoch: u64,
         new_round_event: &NewRoundEvent<Self::Api>,
     );
+
+    #[event("key_rotation_proposed")]
+    fn key_rotation_proposed_event(
+        &self,
+        #[indexed] old_address: &ManagedAddress,
+        #[indexed] new_address: &ManagedAddress,
+    );
+
+    #[event("key_rotation")]
+    fn key_rotation_event(
+        &self,
+        #[indexed] old_address: &ManagedAddress,
+        #[indexed] new_address: &ManagedAddress,
+    );
+
+    #[event("oracle_application")]
+    fn oracle_application_event(
+        &self,
+        #[indexed] candidate: &ManagedAddress,
+        application: &OracleApplication<Self::Api>,
+    );
+
+    #[event("oracle_application_approved")]
+    fn oracle_application_approved_event(&self, #[indexed] candidate: &ManagedAddress);
+
+    #[event("oracle_application_rejected")]
+    fn oracle_application_rejected_event(&self, #[indexed] candidate: &ManagedAddress);
+
+    #[event("admin_action_proposed")]
+    fn admin_action_proposed_event(
+        &self,
+        #[indexed] proposal_id: u64,
+        proposal: &AdminActionProposal<Self::Api>,
+    );
+
+    #[event("admin_action_executed")]
+    fn admin_action_executed_event(&self, #[indexed] proposal_id: u64);
+
+    #[event("timelocked_action_queued")]
+    fn timelocked_action_queued_event(
+        &self,
+        #[indexed] action_id: u64,
+        #[indexed] execution_timestamp: u64,
+        action: &AdminAction<Self::Api>,
+    );
+
+    #[event("timelocked_action_cancelled")]
+    fn timelocked_action_cancelled_event(&self, #[indexed] action_id: u64);
+
+    #[event("timelocked_action_executed")]
+    fn timelocked_action_executed_event(&self, #[indexed] action_id: u64);
+
+    #[event("role_granted")]
+    fn role_granted_event(&self, #[indexed] role: Role, #[indexed] address: &ManagedAddress);
+
+    #[event("role_revoked")]
+    fn role_revoked_event(&self, #[indexed] role: Role, #[indexed] address: &ManagedAddress);
+
+    #[event("price_held")]
+    fn price_held_event(
+        &self,
+        #[indexed] from: &ManagedBuffer,
+        #[indexed] to: &ManagedBuffer,
+        held_price: &TimestampedPrice<Self::Api>,
+    );
+
+    #[event("held_price_released")]
+    fn held_price_released_event(
+        &self,
+        #[indexed] from: &ManagedBuffer,
+        #[indexed] to: &ManagedBuffer,
+        held_price: &TimestampedPrice<Self::Api>,
+    );
+
+    #[event("manual_price_set")]
+    fn manual_price_set_event(
+        &self,
+        #[indexed] from: &ManagedBuffer,
+        #[indexed] to: &ManagedBuffer,
+        manual_price: &ManualPrice<Self::Api>,
+    );
+
+    #[event("submission_replaced")]
+    fn submission_replaced_event(
+        &self,
+        #[indexed] from: &ManagedBuffer,
+        #[indexed] to: &ManagedBuffer,
+        #[indexed] oracle: &ManagedAddress,
+        price: &BigUint,
+    );
+
+    #[event("subscription_approved")]
+    fn subscription_approved_event(
+        &self,
+        #[indexed] from: &ManagedBuffer,
+        #[indexed] to: &ManagedBuffer,
+        #[indexed] consumer: &ManagedAddress,
+    );
+
+    #[event("subscription_removed")]
+    fn subscription_removed_event(
+        &self,
+        #[indexed] from: &ManagedBuffer,
+        #[indexed] to: &ManagedBuffer,
+        #[indexed] consumer: &ManagedAddress,
+    );
+
+    #[event("notification_skipped")]
+    fn notification_skipped_event(
+        &self,
+        #[indexed] from: &ManagedBuffer,
+        #[indexed] to: &ManagedBuffer,
+        #[indexed] consumer: &ManagedAddress,
+        round_id: u32,
+    );
+
+    #[event("revenue_claimed")]
+    fn revenue_claimed_event(
+        &self,
+        #[indexed] oracle: &ManagedAddress,
+        #[indexed] epoch: u64,
+        amount: &BigUint,
+    );
 }
@@ -0,0 +1,108 @@
+use multiversx_sc::imports::*;
+
+use crate::{
+    events,
+    price_aggregator_data::{PairFallback, PriceFeed, Role, TokenPair},
+    price_aggregator_proxy, roles,
+};
+
+#[multiversx_sc::module]
+pub trait FallbackModule:
+    roles::RolesModule + multiversx_sc_modules::pause::PauseModule + events::EventsModule
+{
+    /// The fallback must be another price aggregator, deployed in the same shard,
+    /// as it is read through a synchronous call.
+    /// The local price feed is considered stale once it is older than `max_age_seconds`.
+    #[endpoint(setPairFallback)]
+    fn set_pair_fallback(
+        &self,
+        from: ManagedBuffer,
+        to: ManagedBuffer,
+        address: ManagedAddress,
+        max_age_seconds: u64,
+    ) {
+        self.require_role(Role::PairManager);
+        require!(
+            self.blockchain().is_smart_contract(&address),
+            "fallback must be a smart contract"
+        );
+        require!(
+            address != self.blockchain().get_sc_address(),
+            "fallback must be another contract"
+        );
+
+        self.pair_fallback(&TokenPair { from, to })
+            .set(PairFallback {
+                address,
+                max_age_seconds,
+            });
+    }
+
+    #[endpoint(clearPairFallback)]
+    fn clear_pair_fallback(&self, from: ManagedBuffer, to: ManagedBuffer) {
+        self.require_role(Role::PairManager);
+
+        self.pair_fallback(&TokenPair { from, to }).clear();
+    }
+
+    /// A price feed can only be stale when the pair has a fallback configured.
+    fn is_stale_price_feed(
+        &self,
+        token_pair: &TokenPair<Self::Api>,
+        price_feed: &PriceFeed<Self::Api>,
+    ) -> bool {
+        let fallback_mapper = self.pair_fallback(token_pair);
+        if fallback_mapper.is_empty() {
+            return false;
+        }
+
+        let max_age_seconds = fallback_mapper.get().max_age_seconds;
+        self.blockchain().get_block_timestamp() > price_feed.timestamp + max_age_seconds
+    }
+
+    fn get_fallback_price_feed(&self, token_pair: TokenPair<Self::Api>) -> PriceFeed<Self::Api> {
+        let fallback_mapper = self.pair_fallback(&token_pair);
+        require!(
+            !fallback_mapper.is_empty(),
+            "no fallback configured for pair"
+        );
+
+        let (round_id, from, to, timestamp, price, decimals) = self
+            .tx()
+            .to(fallback_mapper.get().address)
+            .typed(price_aggregator_proxy::PriceAggregatorProxy)
+            .latest_price_feed(token_pair.from, token_pair.to)
+            .returns(ReturnsResult)
+            .sync_call()
+            .into_tuple();
+
+        PriceFeed {
+            round_id,
+            from,
+            to,
+            timestamp,
+            price,
+            decimals,
+        }
+    }
+
+    #[view(getPairFallback)]
+    fn get_pair_fallback(
+        &self,
+        from: ManagedBuffer,
+        to: ManagedBuffer,
+    ) -> OptionalValue<PairFallback<Self::Api>> {
+        let fallback_mapper = self.pair_fallback(&TokenPair { from, to });
+        if fallback_mapper.is_empty() {
+            OptionalValue::None
+        } else {
+            OptionalValue::Some(fallback_mapper.get())
+        }
+    }
+
+    #[storage_mapper("pair_fallback")]
+    fn pair_fallback(
+        &self,
+        token_pair: &TokenPair<Self::Api>,
+    ) -> SingleValueMapper<PairFallback<Self::Api>>;
+}
@@ -0,0 +1,104 @@
+use multiversx_sc::imports::*;
+
+use crate::{board_governance, events, oracle_application, revenue, roles, staking, storage};
+
+#[multiversx_sc::module]
+pub trait KeyRotationModule:
+    staking::StakingModule
+    + storage::StorageModule
+    + events::EventsModule
+    + board_governance::BoardGovernanceModule
+    + revenue::RevenueModule
+    + oracle_application::OracleApplicationModule
+    + roles::RolesModule
+    + multiversx_sc_modules::pause::PauseModule
+{
+    /// First step of a key rotation, called by the oracle with its current address.
+    /// A new proposal overrides the previous one.
+    #[endpoint(proposeKeyRotation)]
+    fn propose_key_rotation(&self, new_address: ManagedAddress) {
+        let caller = self.blockchain().get_caller();
+        require!(
+            self.oracle_status().contains_key(&caller),
+            "only oracles allowed"
+        );
+        self.require_valid_rotation_target(&new_address);
+
+        self.pending_key_rotation(&caller).set(&new_address);
+        self.key_rotation_proposed_event(&caller, &new_address);
+    }
+
+    #[endpoint(cancelKeyRotation)]
+    fn cancel_key_rotation(&self) {
+        let caller = self.blockchain().get_caller();
+        self.pending_key_rotation(&caller).clear();
+    }
+
+    /// Second step of a key rotation, called from the new address.
+    /// Moves the oracle status, stake, board membership, slash, proposal and application votes,
+    /// per-pair assignments, pending submissions and unclaimed revenue to the new address.
+    #[endpoint(acceptKeyRotation)]
+    fn accept_key_rotation(&self, old_address: ManagedAddress) {
+        let caller = self.blockchain().get_caller();
+        let pending_rotation_mapper = self.pending_key_rotation(&old_address);
+        require!(
+            !pending_rotation_mapper.is_empty() && pending_rotation_mapper.get() == caller,
+            "No key rotation proposed for caller"
+        );
+        self.require_valid_rotation_target(&caller);
+        pending_rotation_mapper.clear();
+
+        let mut oracle_mapper = self.oracle_status();
+        let oracle_status = oracle_mapper
+            .remove(&old_address)
+            .unwrap_or_else(|| sc_panic!("only oracles allowed"));
+        let _ = oracle_mapper.insert(caller.clone(), oracle_status);
+
+        self.move_board_member(&old_address, &caller);
+        self.move_proposal_votes(&old_address, &caller);
+        self.move_application_votes(&old_address, &caller);
+        self.move_revenue_id(&old_address, &caller);
+
+        for token_pair in self.restricted_pairs().iter() {
+            let mut pair_oracles_mapper = self.pair_oracles(&token_pair);
+            if pair_oracles_mapper.swap_remove(&old_address) {
+                let _ = pair_oracles_mapper.insert(caller.clone());
+            }
+        }
+
+        for (token_pair, mut pair_submissions) in self.submissions().iter() {
+            if let Some(price) = pair_submissions.remove(&old_address) {
+                let _ = pair_submissions.insert(caller.clone(), price);
+            }
+
+            let mut confidence_mapper = self.submission_confidence(&token_pair);
+            if let Some(confidence) = confidence_mapper.remove(&old_address) {
+                let _ = confidence_mapper.insert(caller.clone(), confidence);
+            }
+        }
+
+        self.key_rotation_event(&old_address, &caller);
+    }
+
+    fn require_valid_rotation_target(&self, new_address: &ManagedAddress) {
+        require!(
+            !self.oracle_status().contains_key(new_address)
+                && !self.user_whitelist().contains(new_address),
+            "New address is already an oracle"
+        );
+        require!(
+            self.oracle_revenue_id(new_address).is_empty(),
+            "New address has unclaimed revenue"
+        );
+        for token in self.staking_token_weights().keys() {
+            require!(
+                self.staked_token_amount(new_address, &token).is_empty(),
+                "New address already has stake"
+            );
+        }
+    }
+
+    #[view(getPendingKeyRotation)]
+    #[storage_mapper("pending_key_rotation")]
+    fn pending_key_rotation(&self, oracle: &ManagedAddress) -> SingleValueMapper<ManagedAddress>;
+}
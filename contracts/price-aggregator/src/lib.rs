@@ -2,24 +2,68 @@
 
 use multiversx_sc::imports::*;
 
+pub mod aggregator_v3;
+pub mod basket;
+pub mod board_governance;
+pub mod circuit_breaker;
+pub mod confidence;
 mod events;
+pub mod fallback;
+pub mod key_rotation;
+pub mod manual_price;
 pub mod median;
+pub mod oracle_application;
+pub mod pair_metadata;
+pub mod pending_round;
 pub mod price_aggregator_data;
-
-use multiversx_sc_modules::staking;
-use price_aggregator_data::{OracleStatus, PriceFeed, TimestampedPrice, TokenPair};
-
-const SUBMISSION_LIST_MAX_LEN: usize = 50;
+pub mod price_aggregator_proxy;
+pub mod revenue;
+pub mod roles;
+pub mod staking;
+pub mod statistics;
+pub mod storage;
+pub mod storage_migration;
+pub mod subscriptions;
+pub mod timelock;
+pub mod update_policy;
+
+use price_aggregator_data::{
+    AdminAction, OracleStatus, PriceFeed, PriceSource, Role, RoundWindow, TimestampedPrice,
+    TokenPair,
+};
+
+pub(crate) const SUBMISSION_LIST_MAX_LEN: usize = 50;
 const SUBMISSION_LIST_MIN_LEN: usize = 3;
 const FIRST_SUBMISSION_TIMESTAMP_MAX_DIFF_SECONDS: u64 = 30;
 pub const MAX_ROUND_DURATION_SECONDS: u64 = 1_800; // 30 minutes
-const PAUSED_ERROR_MSG: &[u8] = b"Contract is paused";
-const PAIR_DECIMALS_NOT_CONFIGURED_ERROR: &[u8] = b"pair decimals not configured";
+pub(crate) const PAUSED_ERROR_MSG: &[u8] = b"Contract is paused";
+pub(crate) const PAIR_DECIMALS_NOT_CONFIGURED_ERROR: &[u8] = b"pair decimals not configured";
 const WRONG_NUMBER_OF_DECIMALS_ERROR: &[u8] = b"wrong number of decimals";
 
 #[multiversx_sc::contract]
 pub trait PriceAggregator:
-    multiversx_sc_modules::pause::PauseModule + staking::StakingModule + events::EventsModule
+    multiversx_sc_modules::pause::PauseModule
+    + staking::StakingModule
+    + storage::StorageModule
+    + key_rotation::KeyRotationModule
+    + oracle_application::OracleApplicationModule
+    + board_governance::BoardGovernanceModule
+    + timelock::TimelockModule
+    + roles::RolesModule
+    + storage_migration::StorageMigrationModule
+    + circuit_breaker::CircuitBreakerModule
+    + manual_price::ManualPriceModule
+    + fallback::FallbackModule
+    + aggregator_v3::AggregatorV3Module
+    + pending_round::PendingRoundModule
+    + update_policy::UpdatePolicyModule
+    + basket::BasketModule
+    + statistics::StatisticsModule
+    + revenue::RevenueModule
+    + confidence::ConfidenceModule
+    + pair_metadata::PairMetadataModule
+    + subscriptions::SubscriptionsModule
+    + events::EventsModule
 {
     #[init]
     fn init(
@@ -39,17 +83,33 @@ pub trait PriceAggregator:
             &oracles.to_vec(),
         );
 
-        self.add_oracles(oracles);
+        self.add_oracles_unchecked(oracles.to_vec());
 
         self.require_valid_submission_count(submission_count);
         self.submission_count().set(submission_count);
 
+        self.storage_version()
+            .set(storage_migration::STORAGE_VERSION);
+
         self.set_paused(true);
     }
 
+    /// Keeps the state of the previous version, migrating it to the current storage layout.
+    #[upgrade]
+    fn upgrade(&self) {
+        self.migrate_storage();
+    }
+
     #[only_owner]
     #[endpoint(changeAmounts)]
     fn change_amounts(&self, staking_amount: BigUint, slash_amount: BigUint) {
+        self.perform_or_queue_admin_action(AdminAction::ChangeAmounts {
+            staking_amount,
+            slash_amount,
+        });
+    }
+
+    fn change_amounts_unchecked(&self, staking_amount: BigUint, slash_amount: BigUint) {
         require!(
             staking_amount > 0 && slash_amount > 0,
             "Staking and slash amount cannot be 0"
@@ -64,7 +124,7 @@ pub trait PriceAggregator:
 
         let mut users_owning_new_amount = 0;
         for user in user_whitelist.iter() {
-            if staking_amount < self.staked_amount(&user).get() {
+            if staking_amount < self.get_staked_amount(&user) {
                 users_owning_new_amount += 1;
             }
             if users_owning_new_amount > slash_quorum {
@@ -81,38 +141,134 @@ pub trait PriceAggregator:
     }
 
     #[only_owner]
+    #[endpoint(setStakingTokenWeight)]
+    fn set_staking_token_weight(&self, token: EgldOrEsdtTokenIdentifier, weight: u64) {
+        self.perform_or_queue_admin_action(AdminAction::SetStakingTokenWeight { token, weight });
+    }
+
+    #[only_owner]
+    #[endpoint(removeStakingToken)]
+    fn remove_staking_token(&self, token: EgldOrEsdtTokenIdentifier) {
+        self.perform_or_queue_admin_action(AdminAction::RemoveStakingToken { token });
+    }
+
     #[endpoint(addOracles)]
     fn add_oracles(&self, oracles: MultiValueEncoded<ManagedAddress>) {
+        self.require_role(Role::OracleManager);
+
+        self.add_oracles_unchecked(oracles.to_vec());
+    }
+
+    fn add_oracles_unchecked(&self, oracles: ManagedVec<ManagedAddress>) {
         let mut oracle_mapper = self.oracle_status();
-        for oracle in oracles {
+        for oracle in oracles.iter() {
             if !oracle_mapper.contains_key(&oracle) {
                 let _ = oracle_mapper.insert(
-                    oracle.clone(),
+                    oracle.clone_value(),
                     OracleStatus {
                         total_submissions: 0,
                         accepted_submissions: 0,
+                        replaced_submissions: 0,
                     },
                 );
-                self.add_board_member(oracle);
+                self.add_board_member(oracle.clone_value());
             }
         }
     }
 
     /// Also receives submission count,
     /// so the owner does not have to update it manually with setSubmissionCount before this call
-    #[only_owner]
     #[endpoint(removeOracles)]
     fn remove_oracles(&self, submission_count: usize, oracles: MultiValueEncoded<ManagedAddress>) {
+        self.require_role(Role::OracleManager);
+
+        self.perform_or_queue_admin_action(AdminAction::RemoveOracles {
+            submission_count,
+            oracles: oracles.to_vec(),
+        });
+    }
+
+    fn remove_oracles_unchecked(
+        &self,
+        submission_count: usize,
+        oracles: ManagedVec<ManagedAddress>,
+    ) {
         let mut oracle_mapper = self.oracle_status();
-        for oracle in oracles {
+        for oracle in oracles.iter() {
             let _ = oracle_mapper.remove(&oracle);
             self.remove_board_member(&oracle);
+            self.pending_key_rotation(&oracle).clear();
+            self.remove_application_votes(&oracle);
+            for token_pair in self.restricted_pairs().iter() {
+                let _ = self.pair_oracles(&token_pair).swap_remove(&oracle);
+            }
         }
 
         self.require_valid_submission_count(submission_count);
         self.submission_count().set(submission_count);
+
+        for token_pair in self.restricted_pairs().iter() {
+            self.require_valid_pair_submission_count(
+                &token_pair,
+                self.pair_submission_count(&token_pair).get(),
+            );
+        }
     }
 
+    /// Restricts the pair to the given oracles.
+    /// Rounds for the pair are then created after `submission_count` submissions from this set.
+    #[endpoint(setPairOracles)]
+    fn set_pair_oracles(
+        &self,
+        from: ManagedBuffer,
+        to: ManagedBuffer,
+        submission_count: usize,
+        oracles: MultiValueEncoded<ManagedAddress>,
+    ) {
+        self.require_role(Role::OracleManager);
+
+        let token_pair = TokenPair { from, to };
+        let mut pair_oracles_mapper = self.pair_oracles(&token_pair);
+        pair_oracles_mapper.clear();
+        for oracle in oracles {
+            require!(
+                self.oracle_status().contains_key(&oracle),
+                "only oracles can be assigned to a pair"
+            );
+            let _ = pair_oracles_mapper.insert(oracle);
+        }
+
+        self.require_valid_pair_submission_count(&token_pair, submission_count);
+        self.pair_submission_count(&token_pair)
+            .set(submission_count);
+        let _ = self.restricted_pairs().insert(token_pair.clone());
+
+        self.clear_submissions(&token_pair);
+    }
+
+    /// Allows every oracle to submit for the pair again.
+    #[endpoint(clearPairOracles)]
+    fn clear_pair_oracles(&self, from: ManagedBuffer, to: ManagedBuffer) {
+        self.require_role(Role::OracleManager);
+
+        let token_pair = TokenPair { from, to };
+        self.pair_oracles(&token_pair).clear();
+        self.pair_submission_count(&token_pair).clear();
+        let _ = self.restricted_pairs().swap_remove(&token_pair);
+
+        self.clear_submissions(&token_pair);
+    }
+
+    #[view(getPairOracles)]
+    fn get_pair_oracles(
+        &self,
+        from: ManagedBuffer,
+        to: ManagedBuffer,
+    ) -> MultiValueEncoded<ManagedAddress> {
+        self.pair_oracles(&TokenPair { from, to }).iter().collect()
+    }
+
+    /// The optional confidence is the half-width of the interval around the price, with the same decimals.
     #[endpoint]
     fn submit(
         &self,
@@ -121,6 +277,7 @@ pub trait PriceAggregator:
         submission_timestamp: u64,
         price: BigUint,
         decimals: u8,
+        opt_confidence: OptionalValue<BigUint>,
     ) {
         self.require_not_paused();
         self.require_is_oracle();
@@ -133,7 +290,14 @@ pub trait PriceAggregator:
 
         self.check_decimals(&from, &to, decimals);
 
-        self.submit_unchecked(from, to, submission_timestamp, price, decimals);
+        self.submit_unchecked(
+            from,
+            to,
+            submission_timestamp,
+            price,
+            decimals,
+            opt_confidence.into_option(),
+        );
     }
 
     fn submit_unchecked(
@@ -143,8 +307,18 @@ pub trait PriceAggregator:
         submission_timestamp: u64,
         price: BigUint,
         decimals: u8,
+        confidence: Option<BigUint>,
     ) {
+        self.require_canonical_pair(&from, &to);
+
         let token_pair = TokenPair { from, to };
+        let caller = self.blockchain().get_caller();
+        let pair_oracles_mapper = self.pair_oracles(&token_pair);
+        require!(
+            pair_oracles_mapper.is_empty() || pair_oracles_mapper.contains(&caller),
+            "oracle not allowed for this pair"
+        );
+
         let mut submissions = self
             .submissions()
             .entry(token_pair.clone())
@@ -172,6 +346,7 @@ pub trait PriceAggregator:
             self.require_valid_first_submission(submission_timestamp, current_timestamp);
 
             submissions.clear();
+            self.submission_confidence(&token_pair).clear();
             first_sub_time_mapper.set(current_timestamp);
             last_sub_time_mapper.set(current_timestamp);
 
@@ -179,24 +354,47 @@ pub trait PriceAggregator:
             is_first_submission = true;
         }
 
-        let caller = self.blockchain().get_caller();
-        let accepted = !submissions.contains_key(&caller)
-            && (is_first_submission || submission_timestamp >= first_submission_timestamp);
+        if is_first_submission {
+            self.require_update_needed(&token_pair, &price);
+        }
+
+        let already_submitted = submissions.contains_key(&caller);
+        let valid_timestamp =
+            is_first_submission || submission_timestamp >= first_submission_timestamp;
+        let accepted = !already_submitted && valid_timestamp;
+        let replaced =
+            already_submitted && valid_timestamp && self.submission_updates_enabled().get();
         if accepted {
-            submissions.insert(caller, price);
+            self.record_submission_confidence(&token_pair, &caller, confidence);
+            submissions.insert(caller.clone(), price);
             last_sub_time_mapper.set(current_timestamp);
+            self.record_epoch_submission(&caller);
 
             self.create_new_round(token_pair, submissions, decimals);
+        } else if replaced {
+            self.submission_replaced_event(&token_pair.from, &token_pair.to, &caller, &price);
+            self.record_submission_confidence(&token_pair, &caller, confidence);
+            submissions.insert(caller.clone(), price);
+            last_sub_time_mapper.set(current_timestamp);
         }
 
         self.oracle_status()
-            .entry(self.blockchain().get_caller())
+            .entry(caller)
             .and_modify(|oracle_status| {
                 oracle_status.accepted_submissions += accepted as u64;
+                oracle_status.replaced_submissions += replaced as u64;
                 oracle_status.total_submissions += 1;
             });
     }
 
+    /// When enabled, a second submission from an oracle replaces its price in the round in progress.
+    #[endpoint(setSubmissionUpdatesEnabled)]
+    fn set_submission_updates_enabled(&self, enabled: bool) {
+        self.require_role(Role::OracleManager);
+
+        self.submission_updates_enabled().set(enabled);
+    }
+
     fn require_valid_first_submission(&self, submission_timestamp: u64, current_timestamp: u64) {
         require!(
             current_timestamp - submission_timestamp <= FIRST_SUBMISSION_TIMESTAMP_MAX_DIFF_SECONDS,
@@ -224,7 +422,41 @@ pub trait PriceAggregator:
 
             self.check_decimals(&from, &to, decimals);
 
-            self.submit_unchecked(from, to, submission_timestamp, price, decimals);
+            self.submit_unchecked(from, to, submission_timestamp, price, decimals, None);
+        }
+    }
+
+    /// Same as `submitBatch`, with the confidence of each price as in `submit`.
+    #[endpoint(submitBatchWithConfidence)]
+    fn submit_batch_with_confidence(
+        &self,
+        submissions: MultiValueEncoded<
+            MultiValue6<ManagedBuffer, ManagedBuffer, u64, BigUint, u8, BigUint>,
+        >,
+    ) {
+        self.require_not_paused();
+        self.require_is_oracle();
+
+        let current_timestamp = self.blockchain().get_block_timestamp();
+        for (from, to, submission_timestamp, price, decimals, confidence) in submissions
+            .into_iter()
+            .map(|submission| submission.into_tuple())
+        {
+            require!(
+                submission_timestamp <= current_timestamp,
+                "Timestamp is from the future"
+            );
+
+            self.check_decimals(&from, &to, decimals);
+
+            self.submit_unchecked(
+                from,
+                to,
+                submission_timestamp,
+                price,
+                decimals,
+                Some(confidence),
+            );
         }
     }
 
@@ -245,43 +477,179 @@ pub trait PriceAggregator:
         )
     }
 
+    fn require_valid_pair_submission_count(
+        &self,
+        token_pair: &TokenPair<Self::Api>,
+        submission_count: usize,
+    ) {
+        require!(
+            submission_count >= SUBMISSION_LIST_MIN_LEN
+                && submission_count <= self.pair_oracles(token_pair).len()
+                && submission_count <= SUBMISSION_LIST_MAX_LEN,
+            "Invalid pair submission count"
+        )
+    }
+
+    fn get_required_submission_count(&self, token_pair: &TokenPair<Self::Api>) -> usize {
+        if self.pair_oracles(token_pair).is_empty() {
+            self.submission_count().get()
+        } else {
+            self.pair_submission_count(token_pair).get()
+        }
+    }
+
     fn create_new_round(
+        &self,
+        token_pair: TokenPair<Self::Api>,
+        submissions: MapMapper<ManagedAddress, BigUint>,
+        decimals: u8,
+    ) {
+        let submissions_len = submissions.len();
+        if submissions_len >= self.get_required_submission_count(&token_pair)
+            || self.is_round_window_complete(&token_pair, submissions_len)
+        {
+            self.finalize_round_unchecked(token_pair, submissions, decimals);
+        }
+    }
+
+    fn finalize_round_unchecked(
         &self,
         token_pair: TokenPair<Self::Api>,
         mut submissions: MapMapper<ManagedAddress, BigUint>,
         decimals: u8,
     ) {
         let submissions_len = submissions.len();
-        if submissions_len >= self.submission_count().get() {
-            require!(
-                submissions_len <= SUBMISSION_LIST_MAX_LEN,
-                "submission list capacity exceeded"
-            );
+        require!(
+            submissions_len <= SUBMISSION_LIST_MAX_LEN,
+            "submission list capacity exceeded"
+        );
 
-            let mut submissions_vec = ArrayVec::<BigUint, SUBMISSION_LIST_MAX_LEN>::new();
-            for submission_value in submissions.values() {
-                submissions_vec.push(submission_value);
-            }
+        let round_timestamp = self.blockchain().get_block_timestamp();
+        let mut submissions_vec =
+            self.take_round_prices(&token_pair, &submissions, round_timestamp);
+
+        let price_result = median::calculate(submissions_vec.as_mut_slice());
+        let price_opt = price_result.unwrap_or_else(|err| sc_panic!(err.as_bytes()));
+        let price = price_opt.unwrap_or_else(|| sc_panic!("no submissions"));
+        let price_feed = TimestampedPrice {
+            price,
+            timestamp: round_timestamp,
+            decimals,
+        };
 
-            let price_result = median::calculate(submissions_vec.as_mut_slice());
-            let price_opt = price_result.unwrap_or_else(|err| sc_panic!(err.as_bytes()));
-            let price = price_opt.unwrap_or_else(|| sc_panic!("no submissions"));
-            let price_feed = TimestampedPrice {
-                price,
-                timestamp: self.blockchain().get_block_timestamp(),
-                decimals,
-            };
+        submissions.clear();
+        self.first_submission_timestamp(&token_pair).clear();
+        self.last_submission_timestamp(&token_pair).clear();
 
-            submissions.clear();
-            self.first_submission_timestamp(&token_pair).clear();
-            self.last_submission_timestamp(&token_pair).clear();
-
-            self.rounds()
-                .entry(token_pair.clone())
-                .or_default()
-                .get()
-                .push(&price_feed);
-            self.emit_new_round_event(&token_pair, &price_feed);
+        self.publish_round(&token_pair, price_feed);
+    }
+
+    /// Rounds of pairs with a round window are also created once the window has passed,
+    /// if they have at least `min_submissions`.
+    fn is_round_window_complete(
+        &self,
+        token_pair: &TokenPair<Self::Api>,
+        submissions_len: usize,
+    ) -> bool {
+        let round_window_mapper = self.pair_round_window(token_pair);
+        if round_window_mapper.is_empty() {
+            return false;
+        }
+
+        let round_window = round_window_mapper.get();
+        let window_end =
+            self.first_submission_timestamp(token_pair).get() + round_window.window_seconds;
+        submissions_len >= round_window.min_submissions
+            && self.blockchain().get_block_timestamp() >= window_end
+    }
+
+    /// Rounds are created at the end of the window if at least `min_submissions` were made,
+    /// instead of waiting for the submission count. The window must end before the round is discarded.
+    /// `min_submissions` cannot go below the minimum submission count nor above the pair's submission count.
+    #[endpoint(setPairRoundWindow)]
+    fn set_pair_round_window(
+        &self,
+        from: ManagedBuffer,
+        to: ManagedBuffer,
+        window_seconds: u64,
+        min_submissions: usize,
+    ) {
+        self.require_role(Role::PairManager);
+        require!(
+            window_seconds > 0 && window_seconds < MAX_ROUND_DURATION_SECONDS,
+            "invalid round window"
+        );
+
+        let token_pair = TokenPair { from, to };
+        require!(
+            min_submissions >= SUBMISSION_LIST_MIN_LEN
+                && min_submissions <= self.get_required_submission_count(&token_pair),
+            "invalid min submissions"
+        );
+
+        self.pair_round_window(&token_pair).set(RoundWindow {
+            window_seconds,
+            min_submissions,
+        });
+    }
+
+    #[endpoint(clearPairRoundWindow)]
+    fn clear_pair_round_window(&self, from: ManagedBuffer, to: ManagedBuffer) {
+        self.require_role(Role::PairManager);
+
+        self.pair_round_window(&TokenPair { from, to }).clear();
+    }
+
+    /// Lets anyone create the round once its window has passed,
+    /// when no further submission arrives to trigger it.
+    #[endpoint(finalizeRound)]
+    fn finalize_round(&self, from: ManagedBuffer, to: ManagedBuffer) {
+        self.require_not_paused();
+
+        let decimals = self.get_pair_decimals(&from, &to);
+        let token_pair = TokenPair { from, to };
+        let round_window_mapper = self.pair_round_window(&token_pair);
+        require!(
+            !round_window_mapper.is_empty(),
+            "no round window for this pair"
+        );
+
+        let submissions = self
+            .submissions()
+            .get(&token_pair)
+            .unwrap_or_else(|| sc_panic!("no pending round"));
+        require!(!submissions.is_empty(), "no pending round");
+
+        let round_window = round_window_mapper.get();
+        let first_submission_timestamp = self.first_submission_timestamp(&token_pair).get();
+        let current_timestamp = self.blockchain().get_block_timestamp();
+        require!(
+            current_timestamp >= first_submission_timestamp + round_window.window_seconds,
+            "round window not elapsed"
+        );
+        require!(
+            current_timestamp <= first_submission_timestamp + MAX_ROUND_DURATION_SECONDS,
+            "round expired"
+        );
+        require!(
+            submissions.len() >= round_window.min_submissions,
+            "not enough submissions"
+        );
+
+        self.finalize_round_unchecked(token_pair, submissions, decimals);
+    }
+
+    #[view(getPairRoundWindow)]
+    fn get_pair_round_window(
+        &self,
+        from: ManagedBuffer,
+        to: ManagedBuffer,
+    ) -> OptionalValue<RoundWindow> {
+        let round_window_mapper = self.pair_round_window(&TokenPair { from, to });
+        if round_window_mapper.is_empty() {
+            OptionalValue::None
+        } else {
+            OptionalValue::Some(round_window_mapper.get())
         }
     }
 
@@ -292,7 +660,51 @@ pub trait PriceAggregator:
 
         let mut result = MultiValueEncoded::new();
         for (token_pair, round_values) in self.rounds().iter() {
-            result.push(self.make_price_feed(token_pair, round_values));
+            result.push(self.make_latest_price_feed(token_pair, Some(round_values)));
+        }
+
+        result
+    }
+
+    /// Pages through the latest price feeds of every pair. An empty page is returned past the last pair.
+    #[view(latestRoundDataPaginated)]
+    fn latest_round_data_paginated(
+        &self,
+        start_index: usize,
+        page_size: usize,
+    ) -> MultiValueEncoded<PriceFeed<Self::Api>> {
+        self.require_not_paused();
+
+        let mut result = MultiValueEncoded::new();
+        for (token_pair, round_values) in self.rounds().iter().skip(start_index).take(page_size) {
+            result.push(self.make_latest_price_feed(token_pair, Some(round_values)));
+        }
+
+        result
+    }
+
+    /// Returns the latest price feed of each requested pair, in order, or `None` for pairs without one.
+    #[view(latestPriceFeeds)]
+    fn latest_price_feeds(
+        &self,
+        pairs: MultiValueEncoded<MultiValue2<ManagedBuffer, ManagedBuffer>>,
+    ) -> MultiValueEncoded<Option<PriceFeed<Self::Api>>> {
+        self.require_not_paused();
+
+        let mut result = MultiValueEncoded::new();
+        for pair in pairs {
+            let (from, to) = pair.into_tuple();
+            let token_pair = TokenPair { from, to };
+            let round_values_opt = self.rounds().get(&token_pair);
+            let has_price_feed =
+                round_values_opt.is_some() || self.get_active_manual_price(&token_pair).is_some();
+
+            let price_feed_opt = if has_price_feed {
+                Some(self.make_latest_price_feed(token_pair, round_values_opt))
+            } else {
+                None
+            };
+            result.push(price_feed_opt);
         }
 
         result
@@ -307,11 +719,88 @@ pub trait PriceAggregator:
         require!(self.not_paused(), PAUSED_ERROR_MSG);
 
         let token_pair = TokenPair { from, to };
-        let round_values = self
-            .rounds()
-            .get(&token_pair)
-            .unwrap_or_else(|| sc_panic!("token pair not found"));
-        let feed = self.make_price_feed(token_pair, round_values);
+        let round_values_opt = self.rounds().get(&token_pair);
+        let feed = self.make_latest_price_feed(token_pair, round_values_opt);
+        (
+            feed.round_id,
+            feed.from,
+            feed.to,
+            feed.timestamp,
+            feed.price,
+            feed.decimals,
+        )
+            .into()
+    }
+
+    /// Same as `latestPriceFeed`, followed by the source of the price: `Oracles` or `Manual`.
+    #[view(latestPriceFeedWithSource)]
+    fn latest_price_feed_with_source(
+        &self,
+        from: ManagedBuffer,
+        to: ManagedBuffer,
+    ) -> MultiValue7<u32, ManagedBuffer, ManagedBuffer, u64, BigUint, u8, PriceSource> {
+        require!(self.not_paused(), PAUSED_ERROR_MSG);
+
+        let token_pair = TokenPair { from, to };
+        let source = self.latest_price_source(token_pair.from.clone(), token_pair.to.clone());
+        let round_values_opt = self.rounds().get(&token_pair);
+        let feed = self.make_latest_price_feed(token_pair, round_values_opt);
+        (
+            feed.round_id,
+            feed.from,
+            feed.to,
+            feed.timestamp,
+            feed.price,
+            feed.decimals,
+            source,
+        )
+            .into()
+    }
+
+    /// Same as `latestPriceFeed`, for consumers paying the pair's access fee.
+    /// The fees are shared among the oracles, see `claimRevenue`.
+    #[payable("*")]
+    #[endpoint(getPriceFeedPaid)]
+    fn get_price_feed_paid(&self, from: ManagedBuffer, to: ManagedBuffer) -> PriceFeed<Self::Api> {
+        require!(self.not_paused(), PAUSED_ERROR_MSG);
+
+        let token_pair = TokenPair { from, to };
+        self.collect_access_fee(&token_pair);
+
+        let round_values_opt = self.rounds().get(&token_pair);
+        self.make_latest_price_feed(token_pair, round_values_opt)
+    }
+
+    /// Reads the pair's fallback aggregator when the contract is paused,
+    /// or when the local price feed is missing or older than the configured maximum age.
+    #[view(latestPriceFeedWithFallback)]
+    fn latest_price_feed_with_fallback(
+        &self,
+        from: ManagedBuffer,
+        to: ManagedBuffer,
+    ) -> MultiValue7<u32, ManagedBuffer, ManagedBuffer, u64, BigUint, u8, PriceSource> {
+        let token_pair = TokenPair { from, to };
+        let round_values_opt = self.rounds().get(&token_pair);
+        let local_source = self.latest_price_source(token_pair.from.clone(), token_pair.to.clone());
+        let has_local_feed = round_values_opt.is_some() || local_source == PriceSource::Manual;
+
+        let (feed, source) = if self.not_paused() && has_local_feed {
+            let feed = self.make_latest_price_feed(token_pair.clone(), round_values_opt);
+            if self.is_stale_price_feed(&token_pair, &feed) {
+                (
+                    self.get_fallback_price_feed(token_pair),
+                    PriceSource::Fallback,
+                )
+            } else {
+                (feed, local_source)
+            }
+        } else {
+            (
+                self.get_fallback_price_feed(token_pair),
+                PriceSource::Fallback,
+            )
+        };
+
         (
             feed.round_id,
             feed.from,
@@ -319,6 +808,7 @@ pub trait PriceAggregator:
             feed.timestamp,
             feed.price,
             feed.decimals,
+            source,
         )
             .into()
     }
@@ -332,13 +822,94 @@ pub trait PriceAggregator:
         Some(self.latest_price_feed(from, to)).into()
     }
 
-    #[only_owner]
     #[endpoint(setSubmissionCount)]
     fn set_submission_count(&self, submission_count: usize) {
+        self.require_role(Role::OracleManager);
+
+        self.perform_or_queue_admin_action(AdminAction::SetSubmissionCount { submission_count });
+    }
+
+    fn set_submission_count_unchecked(&self, submission_count: usize) {
         self.require_valid_submission_count(submission_count);
         self.submission_count().set(submission_count);
     }
 
+    /// Anyone can execute a proposal once enough board members voted for it.
+    #[endpoint(executeAdminAction)]
+    fn execute_admin_action(&self, proposal_id: u64) {
+        let action = self.take_approved_proposal(proposal_id);
+        self.perform_admin_action(action);
+
+        self.admin_action_executed_event(proposal_id);
+    }
+
+    /// Anyone can execute a timelocked action once its execution timestamp has passed.
+    #[endpoint(executeTimelockedAction)]
+    fn execute_timelocked_action(&self, action_id: u64) {
+        let action = self.take_ready_timelocked_action(action_id);
+        self.perform_admin_action(action);
+
+        self.timelocked_action_executed_event(action_id);
+    }
+
+    /// Owner actions are queued in the timelock when a delay is configured.
+    fn perform_or_queue_admin_action(&self, action: AdminAction<Self::Api>) {
+        if self.timelock_delay_seconds().get() == 0 {
+            self.perform_admin_action(action);
+        } else {
+            let _ = self.queue_timelocked_action(action);
+        }
+    }
+
+    fn perform_admin_action(&self, action: AdminAction<Self::Api>) {
+        match action {
+            AdminAction::AddOracles { oracles } => self.add_oracles_unchecked(oracles),
+            AdminAction::RemoveOracles {
+                submission_count,
+                oracles,
+            } => self.remove_oracles_unchecked(submission_count, oracles),
+            AdminAction::SetSubmissionCount { submission_count } => {
+                self.set_submission_count_unchecked(submission_count)
+            }
+            AdminAction::ChangeAmounts {
+                staking_amount,
+                slash_amount,
+            } => self.change_amounts_unchecked(staking_amount, slash_amount),
+            AdminAction::SetPairDecimals { from, to, decimals } => {
+                self.set_pair_decimals_unchecked(from, to, decimals)
+            }
+            AdminAction::SetTimelockDelay { delay_seconds } => {
+                self.timelock_delay_seconds().set(delay_seconds)
+            }
+            AdminAction::SetStakingTokenWeight { token, weight } => {
+                self.set_staking_token_weight_unchecked(token, weight)
+            }
+            AdminAction::RemoveStakingToken { token } => self.remove_staking_token_unchecked(token),
+        }
+    }
+
+    /// An active manual price takes precedence over the last round, keeping the round id.
+    fn make_latest_price_feed(
+        &self,
+        token_pair: TokenPair<Self::Api>,
+        round_values_opt: Option<VecMapper<TimestampedPrice<Self::Api>>>,
+    ) -> PriceFeed<Self::Api> {
+        match self.get_active_manual_price(&token_pair) {
+            Some(manual_price) => PriceFeed {
+                round_id: round_values_opt.map_or(0, |round_values| round_values.len() as u32),
+                from: token_pair.from,
+                to: token_pair.to,
+                timestamp: manual_price.timestamp,
+                price: manual_price.price,
+                decimals: manual_price.decimals,
+            },
+            None => self.make_price_feed(
+                token_pair,
+                round_values_opt.unwrap_or_else(|| sc_panic!("token pair not found")),
+            ),
+        }
+    }
+
     fn make_price_feed(
         &self,
         token_pair: TokenPair<Self::Api>,
@@ -370,13 +941,21 @@ pub trait PriceAggregator:
         if let Some(mut pair_submission_mapper) = self.submissions().get(token_pair) {
             pair_submission_mapper.clear();
         }
+        self.submission_confidence(token_pair).clear();
         self.first_submission_timestamp(token_pair).clear();
         self.last_submission_timestamp(token_pair).clear();
     }
 
-    #[only_owner]
     #[endpoint(setPairDecimals)]
     fn set_pair_decimals(&self, from: ManagedBuffer, to: ManagedBuffer, decimals: u8) {
+        self.require_role(Role::PairManager);
+        self.require_canonical_pair(&from, &to);
+
+        self.perform_or_queue_admin_action(AdminAction::SetPairDecimals { from, to, decimals });
+    }
+
+    /// The contract must be paused when the decimals are actually changed.
+    fn set_pair_decimals_unchecked(&self, from: ManagedBuffer, to: ManagedBuffer, decimals: u8) {
         self.require_paused();
 
         self.pair_decimals(&from, &to).set(Some(decimals));
@@ -398,40 +977,4 @@ pub trait PriceAggregator:
             .get()
             .unwrap_or_else(|| sc_panic!(PAIR_DECIMALS_NOT_CONFIGURED_ERROR))
     }
-
-    #[storage_mapper("pair_decimals")]
-    fn pair_decimals(
-        &self,
-        from: &ManagedBuffer,
-        to: &ManagedBuffer,
-    ) -> SingleValueMapper<Option<u8>>;
-
-    #[view]
-    #[storage_mapper("submission_count")]
-    fn submission_count(&self) -> SingleValueMapper<usize>;
-
-    #[storage_mapper("oracle_status")]
-    fn oracle_status(&self) -> MapMapper<ManagedAddress, OracleStatus>;
-
-    #[storage_mapper("rounds")]
-    fn rounds(
-        &self,
-    ) -> MapStorageMapper<TokenPair<Self::Api>, VecMapper<TimestampedPrice<Self::Api>>>;
-
-    #[storage_mapper("first_submission_timestamp")]
-    fn first_submission_timestamp(
-        &self,
-        token_pair: &TokenPair<Self::Api>,
-    ) -> SingleValueMapper<u64>;
-
-    #[storage_mapper("last_submission_timestamp")]
-    fn last_submission_timestamp(
-        &self,
-        token_pair: &TokenPair<Self::Api>,
-    ) -> SingleValueMapper<u64>;
-
-    #[storage_mapper("submissions")]
-    fn submissions(
-        &self,
-    ) -> MapStorageMapper<TokenPair<Self::Api>, MapMapper<ManagedAddress, BigUint>>;
 }
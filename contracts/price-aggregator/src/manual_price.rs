@@ -0,0 +1,91 @@
+use multiversx_sc::imports::*;
+
+use crate::{
+    events,
+    price_aggregator_data::{ManualPrice, PriceSource, Role, TokenPair},
+    roles, storage, PAIR_DECIMALS_NOT_CONFIGURED_ERROR,
+};
+
+#[multiversx_sc::module]
+pub trait ManualPriceModule:
+    storage::StorageModule
+    + roles::RolesModule
+    + multiversx_sc_modules::pause::PauseModule
+    + events::EventsModule
+{
+    /// Incident response when the oracles cannot submit. Not subject to the timelock.
+    /// The manual price is served until it expires or the next round is created for the pair.
+    #[endpoint(setManualPrice)]
+    fn set_manual_price(
+        &self,
+        from: ManagedBuffer,
+        to: ManagedBuffer,
+        price: BigUint,
+        expires_at: u64,
+    ) {
+        self.require_role(Role::PairManager);
+        require!(price > 0u64, "invalid price");
+
+        let current_timestamp = self.blockchain().get_block_timestamp();
+        require!(
+            expires_at > current_timestamp,
+            "expiry must be in the future"
+        );
+
+        let decimals = self
+            .pair_decimals(&from, &to)
+            .get()
+            .unwrap_or_else(|| sc_panic!(PAIR_DECIMALS_NOT_CONFIGURED_ERROR));
+        let manual_price = ManualPrice {
+            price,
+            timestamp: current_timestamp,
+            decimals,
+            expires_at,
+        };
+        let token_pair = TokenPair { from, to };
+        self.manual_price_set_event(&token_pair.from, &token_pair.to, &manual_price);
+        self.manual_price(&token_pair).set(manual_price);
+    }
+
+    #[endpoint(clearManualPrice)]
+    fn clear_manual_price(&self, from: ManagedBuffer, to: ManagedBuffer) {
+        self.require_role(Role::PairManager);
+
+        self.manual_price(&TokenPair { from, to }).clear();
+    }
+
+    fn get_active_manual_price(
+        &self,
+        token_pair: &TokenPair<Self::Api>,
+    ) -> Option<ManualPrice<Self::Api>> {
+        let manual_price_mapper = self.manual_price(token_pair);
+        if manual_price_mapper.is_empty() {
+            return None;
+        }
+
+        let manual_price = manual_price_mapper.get();
+        if self.blockchain().get_block_timestamp() >= manual_price.expires_at {
+            return None;
+        }
+
+        Some(manual_price)
+    }
+
+    /// Tells whether `latestPriceFeed` currently serves the oracles' price or a manual price.
+    #[view(latestPriceSource)]
+    fn latest_price_source(&self, from: ManagedBuffer, to: ManagedBuffer) -> PriceSource {
+        match self.get_active_manual_price(&TokenPair { from, to }) {
+            Some(_) => PriceSource::Manual,
+            None => PriceSource::Oracles,
+        }
+    }
+
+    #[view(getManualPrice)]
+    fn get_manual_price(
+        &self,
+        from: ManagedBuffer,
+        to: ManagedBuffer,
+    ) -> OptionalValue<ManualPrice<Self::Api>> {
+        self.get_active_manual_price(&TokenPair { from, to }).into()
+    }
+}
@@ -0,0 +1,215 @@
+use multiversx_sc::imports::*;
+
+use crate::{
+    events,
+    price_aggregator_data::{OracleApplication, OracleStatus, Role},
+    roles,
+    staking::{self, STAKE_WEIGHT_PRECISION},
+    storage,
+};
+
+const APPLICATION_NOT_FOUND_ERROR: &[u8] = b"application not found";
+
+#[multiversx_sc::module]
+pub trait OracleApplicationModule:
+    staking::StakingModule
+    + storage::StorageModule
+    + roles::RolesModule
+    + multiversx_sc_modules::pause::PauseModule
+    + events::EventsModule
+{
+    /// Candidates pay the required stake in one of the staking tokens.
+    /// The stake is refunded if the application is rejected.
+    #[payable("*")]
+    #[endpoint(applyAsOracle)]
+    fn apply_as_oracle(&self, operator_name: ManagedBuffer, contact: ManagedBuffer) {
+        let (stake_token, stake_amount) = self.call_value().egld_or_single_fungible_esdt();
+        let weight = self
+            .staking_token_weights()
+            .get(&stake_token)
+            .unwrap_or_else(|| sc_panic!("Invalid payment token"));
+        require!(
+            &stake_amount * weight / STAKE_WEIGHT_PRECISION >= self.required_stake_amount().get(),
+            "Not enough stake"
+        );
+
+        let caller = self.blockchain().get_caller();
+        require!(
+            !self.oracle_status().contains_key(&caller) && !self.user_whitelist().contains(&caller),
+            "Already an oracle"
+        );
+        require!(
+            !self.oracle_applications().contains_key(&caller),
+            "Application already pending"
+        );
+
+        let application = OracleApplication {
+            operator_name,
+            contact,
+            stake_token,
+            stake_amount,
+            timestamp: self.blockchain().get_block_timestamp(),
+        };
+        self.oracle_application_event(&caller, &application);
+        let _ = self.oracle_applications().insert(caller, application);
+    }
+
+    #[endpoint(approveOracleApplication)]
+    fn approve_oracle_application(&self, candidate: ManagedAddress) {
+        self.require_role(Role::OracleManager);
+        self.approve_application(candidate);
+    }
+
+    #[endpoint(rejectOracleApplication)]
+    fn reject_oracle_application(&self, candidate: ManagedAddress) {
+        self.require_role(Role::OracleManager);
+        self.reject_application(candidate);
+    }
+
+    /// Board members vote for or against a candidate.
+    /// The application is resolved as soon as either side reaches the slash quorum,
+    /// counting only votes from members that are still staked board members.
+    #[endpoint(voteOracleApplication)]
+    fn vote_oracle_application(&self, candidate: ManagedAddress, approve: bool) {
+        let caller = self.blockchain().get_caller();
+        require!(
+            self.is_staked_board_member(&caller),
+            "only board members can vote"
+        );
+        require!(
+            self.oracle_applications().contains_key(&candidate),
+            APPLICATION_NOT_FOUND_ERROR
+        );
+
+        let (mut votes_mapper, mut opposing_votes_mapper) = if approve {
+            (
+                self.application_approvals(&candidate),
+                self.application_rejections(&candidate),
+            )
+        } else {
+            (
+                self.application_rejections(&candidate),
+                self.application_approvals(&candidate),
+            )
+        };
+        let _ = opposing_votes_mapper.swap_remove(&caller);
+        let _ = votes_mapper.insert(caller);
+
+        if self.count_staked_voters(&votes_mapper) < self.slash_quorum().get() {
+            return;
+        }
+
+        if approve {
+            self.approve_application(candidate);
+        } else {
+            self.reject_application(candidate);
+        }
+    }
+
+    fn approve_application(&self, candidate: ManagedAddress) {
+        let application = self.take_application(&candidate);
+        require!(
+            !self.oracle_status().contains_key(&candidate),
+            "Already an oracle"
+        );
+
+        self.staked_token_amount(&candidate, &application.stake_token)
+            .update(|amt| *amt += &application.stake_amount);
+        let _ = self.oracle_status().insert(
+            candidate.clone(),
+            OracleStatus {
+                total_submissions: 0,
+                accepted_submissions: 0,
+                replaced_submissions: 0,
+            },
+        );
+        self.add_board_member(candidate.clone());
+
+        self.oracle_application_approved_event(&candidate);
+    }
+
+    fn reject_application(&self, candidate: ManagedAddress) {
+        let application = self.take_application(&candidate);
+
+        self.send().direct(
+            &candidate,
+            &application.stake_token,
+            0,
+            &application.stake_amount,
+        );
+
+        self.oracle_application_rejected_event(&candidate);
+    }
+
+    fn count_staked_voters(&self, voters_mapper: &UnorderedSetMapper<ManagedAddress>) -> usize {
+        voters_mapper
+            .iter()
+            .filter(|voter| self.is_staked_board_member(voter))
+            .count()
+    }
+
+    /// Moves the votes on pending applications from one address to another.
+    fn move_application_votes(&self, old_voter: &ManagedAddress, new_voter: &ManagedAddress) {
+        for candidate in self.oracle_applications().keys() {
+            for mut voters_mapper in [
+                self.application_approvals(&candidate),
+                self.application_rejections(&candidate),
+            ] {
+                if voters_mapper.swap_remove(old_voter) {
+                    let _ = voters_mapper.insert(new_voter.clone());
+                }
+            }
+        }
+    }
+
+    fn remove_application_votes(&self, voter: &ManagedAddress) {
+        for candidate in self.oracle_applications().keys() {
+            let _ = self.application_approvals(&candidate).swap_remove(voter);
+            let _ = self.application_rejections(&candidate).swap_remove(voter);
+        }
+    }
+
+    fn take_application(&self, candidate: &ManagedAddress) -> OracleApplication<Self::Api> {
+        self.application_approvals(candidate).clear();
+        self.application_rejections(candidate).clear();
+        self.oracle_applications()
+            .remove(candidate)
+            .unwrap_or_else(|| sc_panic!(APPLICATION_NOT_FOUND_ERROR))
+    }
+
+    #[view(getOracleApplications)]
+    fn get_oracle_applications(
+        &self,
+    ) -> MultiValueEncoded<MultiValue2<ManagedAddress, OracleApplication<Self::Api>>> {
+        self.oracle_applications()
+            .iter()
+            .map(|(candidate, application)| (candidate, application).into())
+            .collect()
+    }
+
+    /// Only votes from members that are still staked board members are counted.
+    #[view(getApplicationVoteCount)]
+    fn get_application_vote_count(&self, candidate: ManagedAddress, approve: bool) -> usize {
+        let voters_mapper = if approve {
+            self.application_approvals(&candidate)
+        } else {
+            self.application_rejections(&candidate)
+        };
+        self.count_staked_voters(&voters_mapper)
+    }
+
+    #[storage_mapper("oracle_applications")]
+    fn oracle_applications(&self) -> MapMapper<ManagedAddress, OracleApplication<Self::Api>>;
+
+    #[storage_mapper("application_approvals")]
+    fn application_approvals(
+        &self,
+        candidate: &ManagedAddress,
+    ) -> UnorderedSetMapper<ManagedAddress>;
+
+    #[storage_mapper("application_rejections")]
+    fn application_rejections(
+        &self,
+        candidate: &ManagedAddress,
+    ) -> UnorderedSetMapper<ManagedAddress>;
+}
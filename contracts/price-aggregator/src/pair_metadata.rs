@@ -0,0 +1,132 @@
+use multiversx_sc::imports::*;
+
+use crate::{
+    events,
+    price_aggregator_data::{PairMetadata, Role, TokenPair},
+    roles, storage,
+};
+
+pub const MAX_TICKER_LENGTH: usize = 10;
+const NON_CANONICAL_PAIR_ERROR: &[u8] =
+    b"pair tickers must be distinct uppercase alphanumeric tickers";
+
+#[multiversx_sc::module]
+pub trait PairMetadataModule:
+    storage::StorageModule
+    + roles::RolesModule
+    + multiversx_sc_modules::pause::PauseModule
+    + events::EventsModule
+{
+    /// Links the pair to the tokens it prices, for the legs that are ESDTs.
+    #[endpoint(setPairMetadata)]
+    fn set_pair_metadata(
+        &self,
+        from: ManagedBuffer,
+        to: ManagedBuffer,
+        from_token: Option<TokenIdentifier>,
+        to_token: Option<TokenIdentifier>,
+        description: ManagedBuffer,
+        category: ManagedBuffer,
+    ) {
+        self.require_role(Role::PairManager);
+        self.require_canonical_pair(&from, &to);
+        for token in [&from_token, &to_token].into_iter().flatten() {
+            require!(token.is_valid_esdt_identifier(), "invalid token identifier");
+        }
+
+        let token_pair = TokenPair { from, to };
+        self.unlink_pair_tokens(&token_pair);
+        for token in [&from_token, &to_token].into_iter().flatten() {
+            let _ = self.token_pairs(token).insert(token_pair.clone());
+        }
+
+        self.pair_metadata(&token_pair).set(PairMetadata {
+            from_token,
+            to_token,
+            description,
+            category,
+        });
+    }
+
+    #[endpoint(clearPairMetadata)]
+    fn clear_pair_metadata(&self, from: ManagedBuffer, to: ManagedBuffer) {
+        self.require_role(Role::PairManager);
+
+        let token_pair = TokenPair { from, to };
+        self.unlink_pair_tokens(&token_pair);
+        self.pair_metadata(&token_pair).clear();
+    }
+
+    fn unlink_pair_tokens(&self, token_pair: &TokenPair<Self::Api>) {
+        let metadata_mapper = self.pair_metadata(token_pair);
+        if metadata_mapper.is_empty() {
+            return;
+        }
+
+        let metadata = metadata_mapper.get();
+        for token in [&metadata.from_token, &metadata.to_token]
+            .into_iter()
+            .flatten()
+        {
+            let _ = self.token_pairs(token).swap_remove(token_pair);
+        }
+    }
+
+    /// "EGLD/USDC" is the only spelling of the pair, not "egld/usdc" or "EGLD/USDC-c76f1f".
+    fn require_canonical_pair(&self, from: &ManagedBuffer, to: &ManagedBuffer) {
+        require!(
+            is_canonical_ticker(from) && is_canonical_ticker(to) && from != to,
+            NON_CANONICAL_PAIR_ERROR
+        );
+    }
+
+    #[view(getPairMetadata)]
+    fn get_pair_metadata(
+        &self,
+        from: ManagedBuffer,
+        to: ManagedBuffer,
+    ) -> OptionalValue<PairMetadata<Self::Api>> {
+        let metadata_mapper = self.pair_metadata(&TokenPair { from, to });
+        if metadata_mapper.is_empty() {
+            OptionalValue::None
+        } else {
+            OptionalValue::Some(metadata_mapper.get())
+        }
+    }
+
+    /// The pairs whose metadata references the token, on either leg.
+    #[view(getPairsForToken)]
+    fn get_pairs_for_token(
+        &self,
+        token: TokenIdentifier,
+    ) -> MultiValueEncoded<MultiValue2<ManagedBuffer, ManagedBuffer>> {
+        let mut result = MultiValueEncoded::new();
+        for token_pair in self.token_pairs(&token).iter() {
+            result.push((token_pair.from, token_pair.to).into());
+        }
+
+        result
+    }
+
+    #[storage_mapper("pair_metadata")]
+    fn pair_metadata(
+        &self,
+        token_pair: &TokenPair<Self::Api>,
+    ) -> SingleValueMapper<PairMetadata<Self::Api>>;
+
+    #[storage_mapper("token_pairs")]
+    fn token_pairs(&self, token: &TokenIdentifier) -> UnorderedSetMapper<TokenPair<Self::Api>>;
+}
+
+fn is_canonical_ticker<M: ManagedTypeApi>(ticker: &ManagedBuffer<M>) -> bool {
+    let len = ticker.len();
+    if len == 0 || len > MAX_TICKER_LENGTH {
+        return false;
+    }
+
+    let mut buffer = [0u8; MAX_TICKER_LENGTH];
+    let bytes = ticker.load_to_byte_array(&mut buffer);
+    bytes
+        .iter()
+        .all(|byte| byte.is_ascii_uppercase() || byte.is_ascii_digit())
+}
@@ -0,0 +1,72 @@
+use multiversx_sc::imports::*;
+
+use crate::{
+    confidence, events, median,
+    price_aggregator_data::{PendingRound, TokenPair},
+    roles, storage, MAX_ROUND_DURATION_SECONDS, SUBMISSION_LIST_MAX_LEN,
+};
+
+#[multiversx_sc::module]
+pub trait PendingRoundModule:
+    confidence::ConfidenceModule
+    + storage::StorageModule
+    + roles::RolesModule
+    + multiversx_sc_modules::pause::PauseModule
+    + events::EventsModule
+{
+    /// The state of the round in progress for the pair, and the median it would have if it were created now,
+    /// leaving out the submissions the pair's maximum confidence would.
+    /// `seconds_until_discarded` is 0 when the round already expired and will be discarded on the next submission.
+    #[view(getPendingRound)]
+    fn get_pending_round(&self, from: ManagedBuffer, to: ManagedBuffer) -> PendingRound<Self::Api> {
+        let token_pair = TokenPair { from, to };
+        let mut pending_round = PendingRound {
+            submission_count: 0,
+            first_submission_timestamp: 0,
+            last_submission_timestamp: 0,
+            seconds_until_discarded: 0,
+            oracles: ManagedVec::new(),
+            median: None,
+        };
+
+        let submissions = match self.submissions().get(&token_pair) {
+            Some(submissions) if !submissions.is_empty() => submissions,
+            _ => return pending_round,
+        };
+        require!(
+            submissions.len() <= SUBMISSION_LIST_MAX_LEN,
+            "submission list capacity exceeded"
+        );
+
+        for oracle in submissions.keys() {
+            pending_round.oracles.push(oracle);
+        }
+        let (mut round_prices, _) = self.select_round_prices(&token_pair, &submissions);
+        pending_round.median = median::calculate(round_prices.as_mut_slice())
+            .unwrap_or_else(|err| sc_panic!(err.as_bytes()));
+
+        pending_round.submission_count = submissions.len();
+        pending_round.first_submission_timestamp =
+            self.first_submission_timestamp(&token_pair).get();
+        pending_round.last_submission_timestamp = self.last_submission_timestamp(&token_pair).get();
+
+        let discard_timestamp =
+            pending_round.first_submission_timestamp + MAX_ROUND_DURATION_SECONDS;
+        pending_round.seconds_until_discarded =
+            discard_timestamp.saturating_sub(self.blockchain().get_block_timestamp());
+
+        pending_round
+    }
+
+    #[view(hasSubmitted)]
+    fn has_submitted(
+        &self,
+        from: ManagedBuffer,
+        to: ManagedBuffer,
+        oracle: ManagedAddress,
+    ) -> bool {
+        self.submissions()
+            .get(&TokenPair { from, to })
+            .is_some_and(|submissions| submissions.contains_key(&oracle))
+    }
+}
@@ -27,9 +27,201 @@ pub struct TimestampedPrice<M: ManagedTypeApi> {
     pub decimals: u8,
 }
 
+#[type_abi]
+#[derive(NestedEncode, NestedDecode, TopEncode, TopDecode, Debug, PartialEq, Eq)]
+pub struct Subscription<M: ManagedTypeApi> {
+    pub endpoint: ManagedBuffer<M>,
+    pub gas_limit: u64,
+    pub approved: bool,
+    pub consecutive_failures: u32,
+    pub fee: EgldOrEsdtTokenPayment<M>,
+}
+
+#[type_abi]
+#[derive(NestedEncode, NestedDecode, TopEncode, TopDecode, Debug, PartialEq, Eq)]
+pub struct EmaState<M: ManagedTypeApi> {
+    pub value: BigUint<M>,
+    pub decimals: u8,
+    pub timestamp: u64,
+}
+
+#[type_abi]
+#[derive(TopEncode, TopDecode, Debug, PartialEq, Eq)]
+pub struct RealizedVolatility<M: ManagedTypeApi> {
+    pub volatility: BigUint<M>,
+    pub sample_count: usize,
+}
+
+#[type_abi]
+#[derive(ManagedVecItem, NestedEncode, NestedDecode, TopEncode, TopDecode, Clone, Debug)]
+pub struct BasketConstituent<M: ManagedTypeApi> {
+    pub from: ManagedBuffer<M>,
+    pub to: ManagedBuffer<M>,
+    pub weight: BigUint<M>,
+}
+
+#[type_abi]
+#[derive(NestedEncode, NestedDecode, TopEncode, TopDecode, Debug)]
+pub struct Basket<M: ManagedTypeApi> {
+    pub decimals: u8,
+    pub constituents: ManagedVec<M, BasketConstituent<M>>,
+}
+
+#[type_abi]
+#[derive(TopEncode, TopDecode, Debug, PartialEq, Eq)]
+pub struct BasketPrice<M: ManagedTypeApi> {
+    pub price: BigUint<M>,
+    pub decimals: u8,
+    pub timestamp: u64,
+}
+
+#[type_abi]
+#[derive(NestedEncode, NestedDecode, TopEncode, TopDecode, Debug, PartialEq, Eq)]
+pub struct UpdatePolicy {
+    pub deviation_threshold_bps: u64,
+    pub heartbeat_seconds: u64,
+    pub enforced: bool,
+}
+
+#[type_abi]
+#[derive(NestedEncode, NestedDecode, TopEncode, TopDecode, Debug, PartialEq, Eq)]
+pub struct RoundConfidence<M: ManagedTypeApi> {
+    /// The timestamp of the round, matching the one of its price feed.
+    pub timestamp: u64,
+    pub confidence: BigUint<M>,
+    pub sample_count: usize,
+}
+
+#[type_abi]
+#[derive(NestedEncode, NestedDecode, TopEncode, TopDecode, Debug, PartialEq, Eq)]
+pub struct PairMetadata<M: ManagedTypeApi> {
+    pub from_token: Option<TokenIdentifier<M>>,
+    pub to_token: Option<TokenIdentifier<M>>,
+    pub description: ManagedBuffer<M>,
+    pub category: ManagedBuffer<M>,
+}
+
+#[type_abi]
+#[derive(NestedEncode, NestedDecode, TopEncode, TopDecode, Debug, PartialEq, Eq)]
+pub struct RoundWindow {
+    pub window_seconds: u64,
+    pub min_submissions: usize,
+}
+
+#[type_abi]
+#[derive(TopEncode, TopDecode, Debug, PartialEq, Eq)]
+pub struct PendingRound<M: ManagedTypeApi> {
+    pub submission_count: usize,
+    pub first_submission_timestamp: u64,
+    pub last_submission_timestamp: u64,
+    pub seconds_until_discarded: u64,
+    pub oracles: ManagedVec<M, ManagedAddress<M>>,
+    pub median: Option<BigUint<M>>,
+}
+
+#[type_abi]
+#[derive(NestedEncode, NestedDecode, TopEncode, TopDecode, Debug, PartialEq, Eq)]
+pub struct ManualPrice<M: ManagedTypeApi> {
+    pub price: BigUint<M>,
+    pub timestamp: u64,
+    pub decimals: u8,
+    pub expires_at: u64,
+}
+
+#[type_abi]
+#[derive(TopEncode, TopDecode, Debug, PartialEq, Eq)]
+pub enum PriceSource {
+    Oracles,
+    Manual,
+    Fallback,
+}
+
+#[type_abi]
+#[derive(NestedEncode, NestedDecode, TopEncode, TopDecode, Debug, PartialEq, Eq)]
+pub struct PairFallback<M: ManagedTypeApi> {
+    pub address: ManagedAddress<M>,
+    pub max_age_seconds: u64,
+}
+
 #[type_abi]
 #[derive(NestedEncode, NestedDecode, TopEncode, TopDecode, Debug, PartialEq, Eq)]
 pub struct OracleStatus {
     pub accepted_submissions: u64,
     pub total_submissions: u64,
+    pub replaced_submissions: u64,
+}
+
+#[type_abi]
+#[derive(NestedEncode, NestedDecode, TopEncode, TopDecode, Debug, PartialEq, Eq)]
+pub struct CircuitBreakerConfig {
+    pub max_deviation_bps: u64,
+    pub confirmation_window_seconds: u64,
+}
+
+#[type_abi]
+#[derive(NestedEncode, NestedDecode, TopEncode, TopDecode, Debug, PartialEq, Eq)]
+pub struct OracleApplication<M: ManagedTypeApi> {
+    pub operator_name: ManagedBuffer<M>,
+    pub contact: ManagedBuffer<M>,
+    pub stake_token: EgldOrEsdtTokenIdentifier<M>,
+    pub stake_amount: BigUint<M>,
+    pub timestamp: u64,
+}
+
+#[type_abi]
+#[derive(NestedEncode, NestedDecode, TopEncode, TopDecode, Clone)]
+pub enum AdminAction<M: ManagedTypeApi> {
+    AddOracles {
+        oracles: ManagedVec<M, ManagedAddress<M>>,
+    },
+    RemoveOracles {
+        submission_count: usize,
+        oracles: ManagedVec<M, ManagedAddress<M>>,
+    },
+    SetSubmissionCount {
+        submission_count: usize,
+    },
+    ChangeAmounts {
+        staking_amount: BigUint<M>,
+        slash_amount: BigUint<M>,
+    },
+    SetPairDecimals {
+        from: ManagedBuffer<M>,
+        to: ManagedBuffer<M>,
+        decimals: u8,
+    },
+    SetTimelockDelay {
+        delay_seconds: u64,
+    },
+    SetStakingTokenWeight {
+        token: EgldOrEsdtTokenIdentifier<M>,
+        weight: u64,
+    },
+    RemoveStakingToken {
+        token: EgldOrEsdtTokenIdentifier<M>,
+    },
+}
+
+#[type_abi]
+#[derive(NestedEncode, NestedDecode, TopEncode, TopDecode)]
+pub struct AdminActionProposal<M: ManagedTypeApi> {
+    pub action: AdminAction<M>,
+    pub proposer: ManagedAddress<M>,
+    pub deadline: u64,
+}
+
+#[type_abi]
+#[derive(NestedEncode, NestedDecode, TopEncode, TopDecode)]
+pub struct TimelockedAction<M: ManagedTypeApi> {
+    pub action: AdminAction<M>,
+    pub execution_timestamp: u64,
+}
+
+#[type_abi]
+#[derive(NestedEncode, NestedDecode, TopEncode, TopDecode, Clone, Copy, PartialEq, Eq, Debug)]
+pub enum Role {
+    RoleAdmin,
+    PairManager,
+    OracleManager,
+    Pauser,
 }
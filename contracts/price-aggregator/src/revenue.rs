@@ -0,0 +1,190 @@
+use multiversx_sc::imports::*;
+
+use crate::{
+    events,
+    price_aggregator_data::{Role, TokenPair},
+    roles, storage,
+};
+
+#[multiversx_sc::module]
+pub trait RevenueModule:
+    storage::StorageModule
+    + roles::RolesModule
+    + multiversx_sc_modules::pause::PauseModule
+    + events::EventsModule
+{
+    /// Can only be changed before any fee is collected in the current epoch, so that every epoch is paid in one token.
+    #[only_owner]
+    #[endpoint(setAccessFeeToken)]
+    fn set_access_fee_token(&self, token: EgldOrEsdtTokenIdentifier) {
+        let current_epoch = self.blockchain().get_block_epoch();
+        require!(
+            self.epoch_revenue(current_epoch).is_empty(),
+            "fees already collected this epoch"
+        );
+
+        self.access_fee_token().set(token);
+    }
+
+    /// A zero amount disables paid access for the pair.
+    #[endpoint(setPairAccessFee)]
+    fn set_pair_access_fee(&self, from: ManagedBuffer, to: ManagedBuffer, amount: BigUint) {
+        self.require_role(Role::PairManager);
+
+        let fee_mapper = self.pair_access_fee(&TokenPair { from, to });
+        if amount == 0 {
+            fee_mapper.clear();
+        } else {
+            require!(
+                !self.access_fee_token().is_empty(),
+                "access fee token not set"
+            );
+            fee_mapper.set(amount);
+        }
+    }
+
+    fn collect_access_fee(&self, token_pair: &TokenPair<Self::Api>) {
+        let fee_mapper = self.pair_access_fee(token_pair);
+        require!(
+            !fee_mapper.is_empty(),
+            "paid access not enabled for this pair"
+        );
+
+        let payment = self.call_value().egld_or_single_esdt();
+        require!(
+            payment.token_identifier == self.access_fee_token().get()
+                && payment.amount == fee_mapper.get(),
+            "invalid access fee"
+        );
+
+        let current_epoch = self.blockchain().get_block_epoch();
+        self.epoch_revenue(current_epoch)
+            .update(|revenue| *revenue += &payment.amount);
+        self.epoch_revenue_token(current_epoch)
+            .set_if_empty(payment.token_identifier);
+    }
+
+    fn record_epoch_submission(&self, oracle: &ManagedAddress) {
+        let revenue_id = self.get_or_create_revenue_id(oracle);
+        let current_epoch = self.blockchain().get_block_epoch();
+        self.epoch_oracle_submissions(current_epoch, revenue_id)
+            .update(|count| *count += 1);
+        self.epoch_total_submissions(current_epoch)
+            .update(|count| *count += 1);
+    }
+
+    /// Submissions are counted by a stable ID rather than by address,
+    /// so that a key rotation carries the unclaimed revenue of every epoch over to the new address.
+    fn get_or_create_revenue_id(&self, oracle: &ManagedAddress) -> u64 {
+        let revenue_id_mapper = self.oracle_revenue_id(oracle);
+        if !revenue_id_mapper.is_empty() {
+            return revenue_id_mapper.get();
+        }
+
+        let revenue_id = self.last_oracle_revenue_id().update(|id| {
+            *id += 1;
+            *id
+        });
+        revenue_id_mapper.set(revenue_id);
+
+        revenue_id
+    }
+
+    fn move_revenue_id(&self, old_oracle: &ManagedAddress, new_oracle: &ManagedAddress) {
+        let revenue_id = self.oracle_revenue_id(old_oracle).take();
+        if revenue_id != 0 {
+            self.oracle_revenue_id(new_oracle).set(revenue_id);
+        }
+    }
+
+    /// Pays the caller's share of the fees of each given epoch, in proportion to its accepted submissions.
+    /// Only past epochs can be claimed.
+    #[endpoint(claimRevenue)]
+    fn claim_revenue(&self, epochs: MultiValueEncoded<u64>) {
+        let caller = self.blockchain().get_caller();
+        let current_epoch = self.blockchain().get_block_epoch();
+
+        let revenue_id = self.oracle_revenue_id(&caller).get();
+        for epoch in epochs {
+            require!(epoch < current_epoch, "epoch not ended");
+
+            let share = self.get_claimable_revenue(caller.clone(), epoch);
+            self.epoch_oracle_submissions(epoch, revenue_id).clear();
+            if share == 0 {
+                continue;
+            }
+
+            let token = self.epoch_revenue_token(epoch).get();
+            self.tx()
+                .to(&caller)
+                .egld_or_single_esdt(&token, 0, &share)
+                .transfer();
+            self.revenue_claimed_event(&caller, epoch, &share);
+        }
+    }
+
+    /// Fees of an epoch without accepted submissions have no oracle to go to.
+    #[only_owner]
+    #[endpoint(withdrawUnclaimableRevenue)]
+    fn withdraw_unclaimable_revenue(&self, epoch: u64) {
+        require!(
+            epoch < self.blockchain().get_block_epoch(),
+            "epoch not ended"
+        );
+        require!(
+            self.epoch_total_submissions(epoch).get() == 0,
+            "revenue can be claimed by oracles"
+        );
+
+        let revenue = self.epoch_revenue(epoch).take();
+        let token = self.epoch_revenue_token(epoch).take();
+        self.tx()
+            .to(ToCaller)
+            .egld_or_single_esdt(&token, 0, &revenue)
+            .transfer_if_not_empty();
+    }
+
+    #[view(getClaimableRevenue)]
+    fn get_claimable_revenue(&self, oracle: ManagedAddress, epoch: u64) -> BigUint {
+        let total_submissions = self.epoch_total_submissions(epoch).get();
+        if total_submissions == 0 {
+            return BigUint::zero();
+        }
+
+        let revenue_id = self.oracle_revenue_id(&oracle).get();
+        let oracle_submissions = self.epoch_oracle_submissions(epoch, revenue_id).get();
+        self.epoch_revenue(epoch).get() * oracle_submissions / total_submissions
+    }
+
+    /// Zero when paid access is disabled for the pair.
+    #[view(getPairAccessFee)]
+    fn get_pair_access_fee(&self, from: ManagedBuffer, to: ManagedBuffer) -> BigUint {
+        self.pair_access_fee(&TokenPair { from, to }).get()
+    }
+
+    #[view(getAccessFeeToken)]
+    #[storage_mapper("access_fee_token")]
+    fn access_fee_token(&self) -> SingleValueMapper<EgldOrEsdtTokenIdentifier>;
+
+    #[storage_mapper("pair_access_fee")]
+    fn pair_access_fee(&self, token_pair: &TokenPair<Self::Api>) -> SingleValueMapper<BigUint>;
+
+    #[view(getEpochRevenue)]
+    #[storage_mapper("epoch_revenue")]
+    fn epoch_revenue(&self, epoch: u64) -> SingleValueMapper<BigUint>;
+
+    #[storage_mapper("epoch_revenue_token")]
+    fn epoch_revenue_token(&self, epoch: u64) -> SingleValueMapper<EgldOrEsdtTokenIdentifier>;
+
+    #[storage_mapper("oracle_revenue_id")]
+    fn oracle_revenue_id(&self, oracle: &ManagedAddress) -> SingleValueMapper<u64>;
+
+    #[storage_mapper("last_oracle_revenue_id")]
+    fn last_oracle_revenue_id(&self) -> SingleValueMapper<u64>;
+
+    #[storage_mapper("epoch_oracle_submissions")]
+    fn epoch_oracle_submissions(&self, epoch: u64, revenue_id: u64) -> SingleValueMapper<u64>;
+
+    #[storage_mapper("epoch_total_submissions")]
+    fn epoch_total_submissions(&self, epoch: u64) -> SingleValueMapper<u64>;
+}
@@ -0,0 +1,70 @@
+use multiversx_sc::imports::*;
+
+use crate::{events, price_aggregator_data::Role};
+
+#[multiversx_sc::module]
+pub trait RolesModule: multiversx_sc_modules::pause::PauseModule + events::EventsModule {
+    /// Only the owner can grant the role admin role.
+    /// Role admins can grant every other role.
+    #[endpoint(grantRole)]
+    fn grant_role(&self, role: Role, address: ManagedAddress) {
+        self.require_can_manage_role(role);
+
+        if self.role_members(role).insert(address.clone()) {
+            self.role_granted_event(role, &address);
+        }
+    }
+
+    #[endpoint(revokeRole)]
+    fn revoke_role(&self, role: Role, address: ManagedAddress) {
+        self.require_can_manage_role(role);
+
+        if self.role_members(role).swap_remove(&address) {
+            self.role_revoked_event(role, &address);
+        }
+    }
+
+    /// Lets the owner and the pausers pause the contract. Only the owner can unpause it.
+    #[endpoint(emergencyPause)]
+    fn emergency_pause(&self) {
+        self.require_role(Role::Pauser);
+
+        self.set_paused(true);
+        self.pause_event();
+    }
+
+    fn require_can_manage_role(&self, role: Role) {
+        if role == Role::RoleAdmin {
+            let caller = self.blockchain().get_caller();
+            require!(
+                caller == self.blockchain().get_owner_address(),
+                "only owner can manage role admins"
+            );
+        } else {
+            self.require_role(Role::RoleAdmin);
+        }
+    }
+
+    /// The owner implicitly has every role.
+    fn require_role(&self, role: Role) {
+        let caller = self.blockchain().get_caller();
+        require!(
+            self.has_role(role, &caller),
+            "caller does not have the required role"
+        );
+    }
+
+    #[view(hasRole)]
+    fn has_role(&self, role: Role, address: &ManagedAddress) -> bool {
+        address == &self.blockchain().get_owner_address()
+            || self.role_members(role).contains(address)
+    }
+
+    #[view(getRoleMembers)]
+    fn get_role_members(&self, role: Role) -> MultiValueEncoded<ManagedAddress> {
+        self.role_members(role).iter().collect()
+    }
+
+    #[storage_mapper("role_members")]
+    fn role_members(&self, role: Role) -> UnorderedSetMapper<ManagedAddress>;
+}
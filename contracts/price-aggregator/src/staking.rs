@@ -1,4 +1,5 @@
-// TODO: Replace with the one from multiversx_sc_modules on next release
+// Extended copy of the staking module from multiversx_sc_modules.
+// Adds multiple staking tokens with per-token weights and a few views on top of upstream.
 
 use multiversx_sc::derive_imports::*;
 use multiversx_sc::imports::*;
@@ -11,6 +12,9 @@ pub struct TokenAmountPair<M: ManagedTypeApi> {
 
 static NOT_ENOUGH_STAKE_ERR_MSG: &[u8] = b"Not enough stake";
 
+/// Weight of a staking token that counts 1:1 towards the required stake.
+pub const STAKE_WEIGHT_PRECISION: u64 = 10_000;
+
 #[multiversx_sc::module]
 pub trait StakingModule {
     fn init_staking_module(
@@ -37,6 +41,9 @@ pub trait StakingModule {
         );
 
         self.staking_token().set(staking_token);
+        let _ = self
+            .staking_token_weights()
+            .insert(staking_token.clone(), STAKE_WEIGHT_PRECISION);
         self.required_stake_amount().set(staking_amount);
         self.slash_amount().set(slash_amount);
         self.slash_quorum().set(slash_quorum);
@@ -50,8 +57,10 @@ pub trait StakingModule {
     #[endpoint]
     fn stake(&self) {
         let (payment_token, payment_amount) = self.call_value().egld_or_single_fungible_esdt();
-        let staking_token = self.staking_token().get();
-        require!(payment_token == staking_token, "Invalid payment token");
+        require!(
+            self.staking_token_weights().contains_key(&payment_token),
+            "Invalid payment token"
+        );
 
         let caller = self.blockchain().get_caller();
         require!(
@@ -59,33 +68,62 @@ pub trait StakingModule {
             "Only whitelisted members can stake"
         );
 
-        self.staked_amount(&caller)
+        self.staked_token_amount(&caller, &payment_token)
             .update(|amt| *amt += payment_amount);
     }
 
+    /// Unstakes from the given token, or from the token set at deploy if none is given.
     #[endpoint]
-    fn unstake(&self, unstake_amount: BigUint) {
+    fn unstake(
+        &self,
+        unstake_amount: BigUint,
+        opt_token: OptionalValue<EgldOrEsdtTokenIdentifier>,
+    ) {
         let caller = self.blockchain().get_caller();
-        let staked_amount_mapper = self.staked_amount(&caller);
+        self.migrate_legacy_stake(&caller);
+
+        let staking_token = match opt_token {
+            OptionalValue::Some(token) => token,
+            OptionalValue::None => self.staking_token().get(),
+        };
+        let staked_amount_mapper = self.staked_token_amount(&caller, &staking_token);
         let staked_amount = staked_amount_mapper.get();
         require!(unstake_amount <= staked_amount, NOT_ENOUGH_STAKE_ERR_MSG);
 
         let leftover_amount = &staked_amount - &unstake_amount;
-        let required_stake_amount = self.required_stake_amount().get();
+        staked_amount_mapper.set(&leftover_amount);
+
         if self.user_whitelist().contains(&caller) {
+            let required_stake_amount = self.required_stake_amount().get();
             require!(
-                leftover_amount >= required_stake_amount,
+                self.get_staked_amount(&caller) >= required_stake_amount,
                 NOT_ENOUGH_STAKE_ERR_MSG
             );
         }
 
-        staked_amount_mapper.set(&leftover_amount);
-
-        let staking_token = self.staking_token().get();
         self.send()
             .direct(&caller, &staking_token, 0, &unstake_amount);
     }
 
+    /// Adds a new staking token or changes the weight of an existing one.
+    /// A weight of `STAKE_WEIGHT_PRECISION` counts 1:1 towards the required stake.
+    fn set_staking_token_weight_unchecked(&self, token: EgldOrEsdtTokenIdentifier, weight: u64) {
+        require!(weight > 0, "Weight cannot be 0");
+
+        let _ = self.staking_token_weights().insert(token, weight);
+    }
+
+    /// Members keep their balance in the removed token and can still unstake it,
+    /// but it no longer counts towards their stake.
+    fn remove_staking_token_unchecked(&self, token: EgldOrEsdtTokenIdentifier) {
+        require!(
+            token != self.staking_token().get(),
+            "Cannot remove the default staking token"
+        );
+
+        let _ = self.staking_token_weights().remove(&token);
+    }
+
     #[endpoint(voteSlashMember)]
     fn vote_slash_member(&self, member_to_slash: ManagedAddress) {
         require!(
@@ -104,6 +142,15 @@ pub trait StakingModule {
             .insert(caller);
     }
 
+    #[endpoint(cancelVoteSlashMember)]
+    fn cancel_vote_slash_member(&self, member_to_slash: ManagedAddress) {
+        let caller = self.blockchain().get_caller();
+
+        let _ = self
+            .slashing_proposal_voters(&member_to_slash)
+            .swap_remove(&caller);
+    }
+
     #[endpoint(slashMember)]
     fn slash_member(&self, member_to_slash: ManagedAddress) {
         let quorum = self.slash_quorum().get();
@@ -111,19 +158,81 @@ pub trait StakingModule {
         require!(slashing_voters_mapper.len() >= quorum, "Quorum not reached");
 
         let slash_amount = self.slash_amount().get();
-        self.staked_amount(&member_to_slash)
-            .update(|amt| *amt -= &slash_amount);
-        self.total_slashed_amount()
-            .update(|total| *total += slash_amount);
+        self.slash_stake(&member_to_slash, slash_amount);
 
         slashing_voters_mapper.clear();
     }
 
+    /// Takes the weighted slash amount out of the member's stake,
+    /// going through the staking tokens in the order they were added.
+    fn slash_stake(&self, member: &ManagedAddress, slash_amount: BigUint) {
+        let mut remaining = slash_amount;
+        for (token, weight) in self.staking_token_weights().iter() {
+            if remaining == 0 {
+                break;
+            }
+
+            let staked_amount_mapper = self.staked_token_amount(member, &token);
+            let staked_amount = staked_amount_mapper.get();
+            let staked_value = &staked_amount * weight / STAKE_WEIGHT_PRECISION;
+            let slashed_amount = if staked_value <= remaining {
+                remaining -= staked_value;
+                staked_amount.clone()
+            } else {
+                let amount = (&remaining * STAKE_WEIGHT_PRECISION + weight - 1u64) / weight;
+                remaining = BigUint::zero();
+                amount
+            };
+
+            staked_amount_mapper.set(&staked_amount - &slashed_amount);
+            self.total_slashed_amount(&token)
+                .update(|total| *total += slashed_amount);
+        }
+
+        require!(remaining == 0, NOT_ENOUGH_STAKE_ERR_MSG);
+    }
+
+    /// Moves stake recorded by the upstream staking module to the default staking token.
+    fn migrate_legacy_stake(&self, user: &ManagedAddress) {
+        let legacy_staked_amount_mapper = self.legacy_staked_amount(user);
+        if legacy_staked_amount_mapper.is_empty() {
+            return;
+        }
+
+        let amount = legacy_staked_amount_mapper.take();
+        let staking_token = self.staking_token().get();
+        self.staked_token_amount(user, &staking_token)
+            .update(|amt| *amt += amount);
+    }
+
     fn is_staked_board_member(&self, user: &ManagedAddress) -> bool {
         let required_stake = self.required_stake_amount().get();
-        let user_stake = self.staked_amount(user).get();
 
-        self.user_whitelist().contains(user) && user_stake >= required_stake
+        self.user_whitelist().contains(user) && self.get_staked_amount(user) >= required_stake
+    }
+
+    /// Weighted sum of the member's stake over all the accepted staking tokens.
+    #[view(getStakedAmount)]
+    fn get_staked_amount(&self, user: &ManagedAddress) -> BigUint {
+        let mut total = BigUint::zero();
+        for (token, weight) in self.staking_token_weights().iter() {
+            total += self.staked_token_amount(user, &token).get() * weight / STAKE_WEIGHT_PRECISION;
+        }
+
+        total
+    }
+
+    #[view(getBoardMembers)]
+    fn get_board_members(&self) -> MultiValueEncoded<ManagedAddress> {
+        self.user_whitelist().iter().collect()
+    }
+
+    #[view(getStakingTokens)]
+    fn get_staking_tokens(&self) -> MultiValueEncoded<MultiValue2<EgldOrEsdtTokenIdentifier, u64>> {
+        self.staking_token_weights()
+            .iter()
+            .map(|(token, weight)| (token, weight).into())
+            .collect()
     }
 
     #[inline]
@@ -131,6 +240,35 @@ pub trait StakingModule {
         let _ = self.user_whitelist().insert(user);
     }
 
+    /// Moves whitelisting, stake and slash votes from one address to another.
+    /// Balances in tokens that were removed from the staking tokens stay with the old address.
+    fn move_board_member(&self, old_user: &ManagedAddress, new_user: &ManagedAddress) {
+        let mut whitelist_mapper = self.user_whitelist();
+        let was_whitelisted = whitelist_mapper.swap_remove(old_user);
+        require!(was_whitelisted, "Not a board member");
+        let _ = whitelist_mapper.insert(new_user.clone());
+
+        for token in self.staking_token_weights().keys() {
+            let amount = self.staked_token_amount(old_user, &token).take();
+            self.staked_token_amount(new_user, &token)
+                .update(|amt| *amt += amount);
+        }
+
+        for board_member in whitelist_mapper.iter() {
+            let mut voters_mapper = self.slashing_proposal_voters(&board_member);
+            if voters_mapper.swap_remove(old_user) {
+                let _ = voters_mapper.insert(new_user.clone());
+            }
+        }
+
+        let mut old_voters_mapper = self.slashing_proposal_voters(old_user);
+        let mut new_voters_mapper = self.slashing_proposal_voters(new_user);
+        for voter in old_voters_mapper.iter() {
+            let _ = new_voters_mapper.insert(voter);
+        }
+        old_voters_mapper.clear();
+    }
+
     fn remove_board_member(&self, user: &ManagedAddress) {
         let mut whitelist_mapper = self.user_whitelist();
         let was_whitelisted = whitelist_mapper.swap_remove(user);
@@ -156,8 +294,19 @@ pub trait StakingModule {
     #[storage_mapper("staking_module:userWhitelist")]
     fn user_whitelist(&self) -> UnorderedSetMapper<ManagedAddress>;
 
+    #[storage_mapper("staking_module:stakingTokenWeights")]
+    fn staking_token_weights(&self) -> MapMapper<EgldOrEsdtTokenIdentifier, u64>;
+
+    #[view(getStakedTokenAmount)]
+    #[storage_mapper("staking_module:stakedTokenAmount")]
+    fn staked_token_amount(
+        &self,
+        user: &ManagedAddress,
+        token: &EgldOrEsdtTokenIdentifier,
+    ) -> SingleValueMapper<BigUint>;
+
     #[storage_mapper("staking_module:stakedAmount")]
-    fn staked_amount(&self, user: &ManagedAddress) -> SingleValueMapper<BigUint>;
+    fn legacy_staked_amount(&self, user: &ManagedAddress) -> SingleValueMapper<BigUint>;
 
     #[storage_mapper("staking_module:slashingProposalVoters")]
     fn slashing_proposal_voters(
@@ -172,5 +321,9 @@ pub trait StakingModule {
     fn slash_amount(&self) -> SingleValueMapper<BigUint>;
 
     #[storage_mapper("staking_module:totalSlashedAmount")]
-    fn total_slashed_amount(&self) -> SingleValueMapper<BigUint>;
+    fn total_slashed_amount(&self, token: &EgldOrEsdtTokenIdentifier)
+        -> SingleValueMapper<BigUint>;
+
+    #[storage_mapper("staking_module:totalSlashedAmount")]
+    fn legacy_total_slashed_amount(&self) -> SingleValueMapper<BigUint>;
 }
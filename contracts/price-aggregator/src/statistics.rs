@@ -0,0 +1,203 @@
+use multiversx_sc::imports::*;
+
+use crate::{
+    events,
+    price_aggregator_data::{EmaState, RealizedVolatility, Role, TimestampedPrice, TokenPair},
+    roles, storage,
+};
+
+/// Fixed point precision of the decay factors and of the realized volatility (10^18 is 100%).
+pub const STATISTICS_PRECISION: u64 = 1_000_000_000_000_000_000;
+pub const MAX_EMA_HALF_LIVES: usize = 5;
+pub const MAX_VOLATILITY_WINDOW: usize = 100;
+
+#[multiversx_sc::module]
+pub trait StatisticsModule:
+    storage::StorageModule
+    + roles::RolesModule
+    + multiversx_sc_modules::pause::PauseModule
+    + events::EventsModule
+{
+    /// Replaces the tracked half-lives, in seconds. Every EMA series restarts.
+    #[endpoint(setEmaHalfLives)]
+    fn set_ema_half_lives(&self, half_lives: MultiValueEncoded<u64>) {
+        self.require_role(Role::PairManager);
+        require!(
+            half_lives.len() <= MAX_EMA_HALF_LIVES,
+            "too many half-lives"
+        );
+
+        let half_lives = half_lives.to_vec();
+        for half_life in half_lives.iter() {
+            require!(half_life > 0, "invalid half-life");
+        }
+
+        for token_pair in self.rounds().keys() {
+            for half_life in self.ema_half_lives().get().iter() {
+                self.ema(&token_pair, half_life).clear();
+            }
+        }
+        self.ema_half_lives().set(half_lives);
+    }
+
+    /// Sets the number of rounds used for the realized volatility. The series restarts.
+    #[endpoint(setVolatilityWindow)]
+    fn set_volatility_window(&self, window: usize) {
+        self.require_role(Role::PairManager);
+        require!(window <= MAX_VOLATILITY_WINDOW, "invalid volatility window");
+
+        for token_pair in self.rounds().keys() {
+            self.squared_returns(&token_pair).clear();
+            self.next_return_index(&token_pair).clear();
+        }
+        self.volatility_window().set(window);
+    }
+
+    /// Called with every round, before it is recorded.
+    fn update_price_statistics(
+        &self,
+        token_pair: &TokenPair<Self::Api>,
+        price_feed: &TimestampedPrice<Self::Api>,
+    ) {
+        for half_life in self.ema_half_lives().get().iter() {
+            self.update_ema(token_pair, half_life, price_feed);
+        }
+
+        let window = self.volatility_window().get();
+        if window == 0 {
+            return;
+        }
+
+        let last_price = match self.rounds().get(token_pair) {
+            Some(rounds) if !rounds.is_empty() => rounds.get(rounds.len()),
+            _ => return,
+        };
+        if last_price.decimals != price_feed.decimals || last_price.price == 0u64 {
+            return;
+        }
+
+        let difference = if price_feed.price > last_price.price {
+            &price_feed.price - &last_price.price
+        } else {
+            &last_price.price - &price_feed.price
+        };
+        let price_return = difference * STATISTICS_PRECISION / &last_price.price;
+        let squared_return = &price_return * &price_return;
+
+        let mut squared_returns = self.squared_returns(token_pair);
+        if squared_returns.len() < window {
+            squared_returns.push(&squared_return);
+        } else {
+            let index = self.next_return_index(token_pair).get();
+            squared_returns.set(index + 1, &squared_return);
+            self.next_return_index(token_pair).set((index + 1) % window);
+        }
+    }
+
+    fn update_ema(
+        &self,
+        token_pair: &TokenPair<Self::Api>,
+        half_life: u64,
+        price_feed: &TimestampedPrice<Self::Api>,
+    ) {
+        let ema_mapper = self.ema(token_pair, half_life);
+        let value = if ema_mapper.is_empty() {
+            price_feed.price.clone()
+        } else {
+            let ema = ema_mapper.get();
+            if ema.decimals != price_feed.decimals {
+                price_feed.price.clone()
+            } else {
+                let elapsed = price_feed.timestamp.saturating_sub(ema.timestamp);
+                let decay = BigUint::from(decay_factor(elapsed, half_life));
+                let precision = BigUint::from(STATISTICS_PRECISION);
+                (ema.value * &decay + &price_feed.price * &(&precision - &decay)) / precision
+            }
+        };
+
+        ema_mapper.set(EmaState {
+            value,
+            decimals: price_feed.decimals,
+            timestamp: price_feed.timestamp,
+        });
+    }
+
+    #[view(getEma)]
+    fn get_ema(
+        &self,
+        from: ManagedBuffer,
+        to: ManagedBuffer,
+        half_life: u64,
+    ) -> OptionalValue<EmaState<Self::Api>> {
+        let ema_mapper = self.ema(&TokenPair { from, to }, half_life);
+        if ema_mapper.is_empty() {
+            OptionalValue::None
+        } else {
+            OptionalValue::Some(ema_mapper.get())
+        }
+    }
+
+    /// The root mean square of the relative price changes over the last rounds of the pair.
+    #[view(getRealizedVolatility)]
+    fn get_realized_volatility(
+        &self,
+        from: ManagedBuffer,
+        to: ManagedBuffer,
+    ) -> RealizedVolatility<Self::Api> {
+        let squared_returns = self.squared_returns(&TokenPair { from, to });
+        let sample_count = squared_returns.len();
+        if sample_count == 0 {
+            return RealizedVolatility {
+                volatility: BigUint::zero(),
+                sample_count,
+            };
+        }
+
+        let mut sum = BigUint::zero();
+        for squared_return in squared_returns.iter() {
+            sum += squared_return;
+        }
+
+        RealizedVolatility {
+            volatility: (sum / sample_count as u64).sqrt(),
+            sample_count,
+        }
+    }
+
+    #[view(getEmaHalfLives)]
+    #[storage_mapper("ema_half_lives")]
+    fn ema_half_lives(&self) -> SingleValueMapper<ManagedVec<u64>>;
+
+    #[storage_mapper("ema")]
+    fn ema(
+        &self,
+        token_pair: &TokenPair<Self::Api>,
+        half_life: u64,
+    ) -> SingleValueMapper<EmaState<Self::Api>>;
+
+    #[view(getVolatilityWindow)]
+    #[storage_mapper("volatility_window")]
+    fn volatility_window(&self) -> SingleValueMapper<usize>;
+
+    #[storage_mapper("squared_returns")]
+    fn squared_returns(&self, token_pair: &TokenPair<Self::Api>) -> VecMapper<BigUint>;
+
+    #[storage_mapper("next_return_index")]
+    fn next_return_index(&self, token_pair: &TokenPair<Self::Api>) -> SingleValueMapper<usize>;
+}
+
+/// Weight of the previous EMA value after `elapsed` seconds, 2^(-elapsed / half_life).
+/// Whole half-lives are exact, the remaining fraction uses a quadratic approximation (error below 0.5%).
+fn decay_factor(elapsed: u64, half_life: u64) -> u64 {
+    let halvings = elapsed / half_life;
+    if halvings >= 64 {
+        return 0;
+    }
+
+    let precision = STATISTICS_PRECISION as u128;
+    let fraction = ((elapsed % half_life) as u128) * precision / half_life as u128;
+    let partial_decay =
+        precision - fraction * 6_565 / 10_000 + fraction * fraction / precision * 1_565 / 10_000;
+
+    (partial_decay >> halvings) as u64
+}
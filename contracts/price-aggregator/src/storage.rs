@@ -0,0 +1,78 @@
+use multiversx_sc::imports::*;
+
+use crate::price_aggregator_data::{
+    ManualPrice, OracleStatus, RoundWindow, TimestampedPrice, TokenPair,
+};
+
+#[multiversx_sc::module]
+pub trait StorageModule {
+    #[storage_mapper("pair_decimals")]
+    fn pair_decimals(
+        &self,
+        from: &ManagedBuffer,
+        to: &ManagedBuffer,
+    ) -> SingleValueMapper<Option<u8>>;
+
+    #[view]
+    #[storage_mapper("submission_count")]
+    fn submission_count(&self) -> SingleValueMapper<usize>;
+
+    #[view(getSubmissionUpdatesEnabled)]
+    #[storage_mapper("submission_updates_enabled")]
+    fn submission_updates_enabled(&self) -> SingleValueMapper<bool>;
+
+    #[storage_mapper("oracle_status")]
+    fn oracle_status(&self) -> MapMapper<ManagedAddress, OracleStatus>;
+
+    #[storage_mapper("rounds")]
+    fn rounds(
+        &self,
+    ) -> MapStorageMapper<TokenPair<Self::Api>, VecMapper<TimestampedPrice<Self::Api>>>;
+
+    #[storage_mapper("first_submission_timestamp")]
+    fn first_submission_timestamp(
+        &self,
+        token_pair: &TokenPair<Self::Api>,
+    ) -> SingleValueMapper<u64>;
+
+    #[storage_mapper("last_submission_timestamp")]
+    fn last_submission_timestamp(
+        &self,
+        token_pair: &TokenPair<Self::Api>,
+    ) -> SingleValueMapper<u64>;
+
+    #[storage_mapper("submissions")]
+    fn submissions(
+        &self,
+    ) -> MapStorageMapper<TokenPair<Self::Api>, MapMapper<ManagedAddress, BigUint>>;
+
+    /// Confidence intervals attached to the submissions of the round in progress.
+    #[storage_mapper("submission_confidence")]
+    fn submission_confidence(
+        &self,
+        token_pair: &TokenPair<Self::Api>,
+    ) -> MapMapper<ManagedAddress, BigUint>;
+
+    /// Oracles allowed to submit for the pair. Empty means every oracle is allowed.
+    #[storage_mapper("pair_oracles")]
+    fn pair_oracles(&self, token_pair: &TokenPair<Self::Api>)
+        -> UnorderedSetMapper<ManagedAddress>;
+
+    #[storage_mapper("pair_submission_count")]
+    fn pair_submission_count(&self, token_pair: &TokenPair<Self::Api>) -> SingleValueMapper<usize>;
+
+    #[storage_mapper("pair_round_window")]
+    fn pair_round_window(
+        &self,
+        token_pair: &TokenPair<Self::Api>,
+    ) -> SingleValueMapper<RoundWindow>;
+
+    #[storage_mapper("restricted_pairs")]
+    fn restricted_pairs(&self) -> UnorderedSetMapper<TokenPair<Self::Api>>;
+
+    #[storage_mapper("manual_price")]
+    fn manual_price(
+        &self,
+        token_pair: &TokenPair<Self::Api>,
+    ) -> SingleValueMapper<ManualPrice<Self::Api>>;
+}
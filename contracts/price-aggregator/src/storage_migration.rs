@@ -0,0 +1,111 @@
+use multiversx_sc::{derive_imports::*, imports::*, storage::StorageKey};
+
+use crate::{
+    price_aggregator_data::{OracleStatus, TimestampedPrice, TokenPair},
+    staking::{self, STAKE_WEIGHT_PRECISION},
+    storage,
+};
+
+/// Bumped whenever the encoding of stored data changes, together with a new migration step.
+pub const STORAGE_VERSION: u32 = 3;
+/// Contracts deployed before the version marker was introduced.
+const LEGACY_STORAGE_VERSION: u32 = 1;
+
+/// `OracleStatus` before replaced submissions were counted (storage version 2).
+#[derive(NestedEncode, NestedDecode, TopEncode, TopDecode)]
+pub struct OracleStatusV2 {
+    pub accepted_submissions: u64,
+    pub total_submissions: u64,
+}
+
+#[multiversx_sc::module]
+pub trait StorageMigrationModule: staking::StakingModule + storage::StorageModule {
+    /// Runs every migration step between the stored version and the current one.
+    fn migrate_storage(&self) {
+        let version_mapper = self.storage_version();
+        let mut version = if version_mapper.is_empty() {
+            LEGACY_STORAGE_VERSION
+        } else {
+            version_mapper.get()
+        };
+        require!(
+            version <= STORAGE_VERSION,
+            "storage version is newer than the contract"
+        );
+
+        while version < STORAGE_VERSION {
+            match version {
+                1 => self.migrate_staking_storage(),
+                2 => self.migrate_oracle_status_encoding::<OracleStatusV2>(|status| OracleStatus {
+                    accepted_submissions: status.accepted_submissions,
+                    total_submissions: status.total_submissions,
+                    replaced_submissions: 0,
+                }),
+                _ => sc_panic!("unknown storage version"),
+            }
+            version += 1;
+        }
+
+        version_mapper.set(STORAGE_VERSION);
+    }
+
+    /// v1 -> v2: the upstream staking module kept a single staking token.
+    /// Stake of members that are no longer whitelisted is migrated when they unstake.
+    fn migrate_staking_storage(&self) {
+        let staking_token = self.staking_token().get();
+        let mut weights_mapper = self.staking_token_weights();
+        if !weights_mapper.contains_key(&staking_token) {
+            let _ = weights_mapper.insert(staking_token.clone(), STAKE_WEIGHT_PRECISION);
+        }
+
+        for user in self.user_whitelist().iter() {
+            self.migrate_legacy_stake(&user);
+        }
+
+        let legacy_total_slashed_amount = self.legacy_total_slashed_amount().take();
+        self.total_slashed_amount(&staking_token)
+            .update(|total| *total += legacy_total_slashed_amount);
+    }
+
+    /// Re-encodes the stored oracle statuses, for steps that change the `OracleStatus` layout.
+    fn migrate_oracle_status_encoding<Legacy>(&self, convert: fn(Legacy) -> OracleStatus)
+    where
+        Legacy: TopEncode + TopDecode + NestedEncode + NestedDecode + 'static,
+    {
+        let mut legacy_mapper =
+            MapMapper::<Self::Api, ManagedAddress, Legacy>::new(StorageKey::new(b"oracle_status"));
+        let mut oracle_mapper = self.oracle_status();
+
+        // inserting over a legacy value would decode it with the new layout, so it is removed first
+        let oracles: ManagedVec<ManagedAddress> = legacy_mapper.keys().collect();
+        for oracle in oracles.iter() {
+            if let Some(legacy_status) = legacy_mapper.remove(&oracle) {
+                let _ = oracle_mapper.insert(oracle.clone_value(), convert(legacy_status));
+            }
+        }
+    }
+
+    /// Re-encodes every stored round, for steps that change the `TimestampedPrice` layout.
+    fn migrate_rounds_encoding<Legacy>(&self, convert: fn(Legacy) -> TimestampedPrice<Self::Api>)
+    where
+        Legacy: TopEncode + TopDecode + 'static,
+    {
+        let legacy_mapper =
+            MapStorageMapper::<Self::Api, TokenPair<Self::Api>, VecMapper<Self::Api, Legacy>>::new(
+                StorageKey::new(b"rounds"),
+            );
+        for (token_pair, legacy_rounds) in legacy_mapper.iter() {
+            let mut rounds = self
+                .rounds()
+                .get(&token_pair)
+                .unwrap_or_else(|| sc_panic!("token pair not found"));
+            for round_id in 1..=legacy_rounds.len() {
+                rounds.set(round_id, &convert(legacy_rounds.get(round_id)));
+            }
+        }
+    }
+
+    #[view(getStorageVersion)]
+    #[storage_mapper("storage_version")]
+    fn storage_version(&self) -> SingleValueMapper<u32>;
+}
@@ -0,0 +1,252 @@
+use multiversx_sc::imports::*;
+
+use crate::{
+    events,
+    price_aggregator_data::{PriceFeed, Subscription, TimestampedPrice, TokenPair},
+    storage,
+};
+
+pub const MAX_SUBSCRIBERS_PER_PAIR: usize = 10;
+pub const MAX_NOTIFICATION_GAS: u64 = 20_000_000;
+/// Gas kept for the rest of the submission after the notifications are sent.
+pub const NOTIFICATION_GAS_RESERVE: u64 = 2_000_000;
+pub const MAX_CONSECUTIVE_NOTIFICATION_FAILURES: u32 = 3;
+const SUBSCRIPTION_NOT_FOUND_ERROR: &[u8] = b"subscription not found";
+
+#[multiversx_sc::module]
+pub trait SubscriptionsModule: storage::StorageModule + events::EventsModule {
+    /// Requests price notifications for the pair, delivered by calling `endpoint` with the `PriceFeed`.
+    /// The subscription fee, if configured, is paid here and refunded if the owner rejects the subscription.
+    #[payable("*")]
+    #[endpoint(subscribe)]
+    fn subscribe(
+        &self,
+        from: ManagedBuffer,
+        to: ManagedBuffer,
+        endpoint: ManagedBuffer,
+        gas_limit: u64,
+    ) {
+        let consumer = self.blockchain().get_caller();
+        require!(
+            self.blockchain().is_smart_contract(&consumer),
+            "only smart contracts can subscribe"
+        );
+        require!(!endpoint.is_empty(), "invalid endpoint");
+        require!(
+            gas_limit > 0 && gas_limit <= MAX_NOTIFICATION_GAS,
+            "invalid gas limit"
+        );
+
+        let fee = self.call_value().egld_or_single_esdt();
+        let fee_mapper = self.subscription_fee();
+        if !fee_mapper.is_empty() {
+            let required_fee = fee_mapper.get();
+            require!(
+                fee.token_identifier == required_fee.token_identifier
+                    && fee.amount == required_fee.amount,
+                "invalid subscription fee"
+            );
+        } else {
+            require!(fee.amount == 0, "no subscription fee required");
+        }
+
+        let token_pair = TokenPair { from, to };
+        let subscription_mapper = self.subscription(&token_pair, &consumer);
+        require!(subscription_mapper.is_empty(), "already subscribed");
+
+        subscription_mapper.set(Subscription {
+            endpoint,
+            gas_limit,
+            approved: false,
+            consecutive_failures: 0,
+            fee,
+        });
+    }
+
+    /// Sends the subscription fee to the owner.
+    #[only_owner]
+    #[endpoint(approveSubscription)]
+    fn approve_subscription(
+        &self,
+        from: ManagedBuffer,
+        to: ManagedBuffer,
+        consumer: ManagedAddress,
+    ) {
+        let token_pair = TokenPair { from, to };
+        let subscription_mapper = self.subscription(&token_pair, &consumer);
+        require!(
+            !subscription_mapper.is_empty(),
+            SUBSCRIPTION_NOT_FOUND_ERROR
+        );
+
+        let mut subscription = subscription_mapper.get();
+        require!(!subscription.approved, "subscription already approved");
+        require!(
+            self.subscribers(&token_pair).len() < MAX_SUBSCRIBERS_PER_PAIR,
+            "too many subscribers"
+        );
+
+        self.tx()
+            .to(ToCaller)
+            .payment(&subscription.fee)
+            .transfer_if_not_empty();
+
+        subscription.approved = true;
+        subscription.fee = EgldOrEsdtTokenPayment::no_payment();
+        subscription_mapper.set(subscription);
+        let _ = self.subscribers(&token_pair).insert(consumer.clone());
+
+        self.subscription_approved_event(&token_pair.from, &token_pair.to, &consumer);
+    }
+
+    /// Removes a pending or approved subscription, refunding the fee of a pending one.
+    #[only_owner]
+    #[endpoint(removeSubscription)]
+    fn remove_subscription(
+        &self,
+        from: ManagedBuffer,
+        to: ManagedBuffer,
+        consumer: ManagedAddress,
+    ) {
+        self.remove_subscription_unchecked(&TokenPair { from, to }, &consumer);
+    }
+
+    #[endpoint(unsubscribe)]
+    fn unsubscribe(&self, from: ManagedBuffer, to: ManagedBuffer) {
+        let consumer = self.blockchain().get_caller();
+        self.remove_subscription_unchecked(&TokenPair { from, to }, &consumer);
+    }
+
+    /// A zero amount removes the fee.
+    #[only_owner]
+    #[endpoint(setSubscriptionFee)]
+    fn set_subscription_fee(&self, token: EgldOrEsdtTokenIdentifier, amount: BigUint) {
+        if amount == 0 {
+            self.subscription_fee().clear();
+        } else {
+            self.subscription_fee()
+                .set(EgldOrEsdtTokenPayment::new(token, 0, amount));
+        }
+    }
+
+    fn remove_subscription_unchecked(
+        &self,
+        token_pair: &TokenPair<Self::Api>,
+        consumer: &ManagedAddress,
+    ) {
+        let subscription_mapper = self.subscription(token_pair, consumer);
+        require!(
+            !subscription_mapper.is_empty(),
+            SUBSCRIPTION_NOT_FOUND_ERROR
+        );
+
+        let subscription = subscription_mapper.take();
+        self.subscribers(token_pair).swap_remove(consumer);
+        self.tx()
+            .to(consumer)
+            .payment(&subscription.fee)
+            .transfer_if_not_empty();
+
+        self.subscription_removed_event(&token_pair.from, &token_pair.to, consumer);
+    }
+
+    /// Fire and forget calls to every approved subscriber.
+    /// Failures on the consumer side are not reported back, so only notifications skipped
+    /// for lack of gas count as failures. Subscriptions skipped repeatedly are removed.
+    fn notify_subscribers(
+        &self,
+        token_pair: &TokenPair<Self::Api>,
+        round_id: usize,
+        price_feed: &TimestampedPrice<Self::Api>,
+    ) {
+        let subscribers = self.subscribers(token_pair);
+        if subscribers.is_empty() {
+            return;
+        }
+
+        let notification = PriceFeed {
+            round_id: round_id as u32,
+            from: token_pair.from.clone(),
+            to: token_pair.to.clone(),
+            timestamp: price_feed.timestamp,
+            price: price_feed.price.clone(),
+            decimals: price_feed.decimals,
+        };
+        let mut failed_consumers = ManagedVec::<Self::Api, ManagedAddress>::new();
+        for consumer in subscribers.iter() {
+            let subscription_mapper = self.subscription(token_pair, &consumer);
+            let mut subscription = subscription_mapper.get();
+            let gas_left = self.blockchain().get_gas_left();
+            if gas_left < subscription.gas_limit + NOTIFICATION_GAS_RESERVE {
+                self.notification_skipped_event(
+                    &token_pair.from,
+                    &token_pair.to,
+                    &consumer,
+                    notification.round_id,
+                );
+                subscription.consecutive_failures += 1;
+                if subscription.consecutive_failures >= MAX_CONSECUTIVE_NOTIFICATION_FAILURES {
+                    failed_consumers.push(consumer);
+                } else {
+                    subscription_mapper.set(subscription);
+                }
+                continue;
+            }
+
+            self.tx()
+                .to(&consumer)
+                .raw_call(subscription.endpoint.clone())
+                .argument(&notification)
+                .gas(subscription.gas_limit)
+                .transfer_execute();
+
+            if subscription.consecutive_failures > 0 {
+                subscription.consecutive_failures = 0;
+                subscription_mapper.set(subscription);
+            }
+        }
+
+        for consumer in failed_consumers.iter() {
+            self.remove_subscription_unchecked(token_pair, &consumer);
+        }
+    }
+
+    #[view(getSubscription)]
+    fn get_subscription(
+        &self,
+        from: ManagedBuffer,
+        to: ManagedBuffer,
+        consumer: ManagedAddress,
+    ) -> OptionalValue<Subscription<Self::Api>> {
+        let subscription_mapper = self.subscription(&TokenPair { from, to }, &consumer);
+        if subscription_mapper.is_empty() {
+            OptionalValue::None
+        } else {
+            OptionalValue::Some(subscription_mapper.get())
+        }
+    }
+
+    #[view(getSubscribers)]
+    fn get_subscribers(
+        &self,
+        from: ManagedBuffer,
+        to: ManagedBuffer,
+    ) -> MultiValueEncoded<ManagedAddress> {
+        self.subscribers(&TokenPair { from, to }).iter().collect()
+    }
+
+    #[view(getSubscriptionFee)]
+    #[storage_mapper("subscription_fee")]
+    fn subscription_fee(&self) -> SingleValueMapper<EgldOrEsdtTokenPayment>;
+
+    #[storage_mapper("subscription")]
+    fn subscription(
+        &self,
+        token_pair: &TokenPair<Self::Api>,
+        consumer: &ManagedAddress,
+    ) -> SingleValueMapper<Subscription<Self::Api>>;
+
+    /// Approved subscribers only.
+    #[storage_mapper("subscribers")]
+    fn subscribers(&self, token_pair: &TokenPair<Self::Api>) -> UnorderedSetMapper<ManagedAddress>;
+}
@@ -0,0 +1,86 @@
+use multiversx_sc::imports::*;
+
+use crate::{
+    events,
+    price_aggregator_data::{AdminAction, TimelockedAction},
+};
+
+pub const TIMELOCK_GRACE_PERIOD_SECONDS: u64 = 14 * 24 * 60 * 60; // 14 days
+const ACTION_NOT_FOUND_ERROR: &[u8] = b"timelocked action not found";
+
+#[multiversx_sc::module]
+pub trait TimelockModule: events::EventsModule {
+    /// Increasing the delay takes effect immediately,
+    /// while decreasing it goes through the current timelock.
+    #[only_owner]
+    #[endpoint(setTimelockDelay)]
+    fn set_timelock_delay(&self, delay_seconds: u64) {
+        if delay_seconds >= self.timelock_delay_seconds().get() {
+            self.timelock_delay_seconds().set(delay_seconds);
+        } else {
+            self.queue_timelocked_action(AdminAction::SetTimelockDelay { delay_seconds });
+        }
+    }
+
+    #[only_owner]
+    #[endpoint(cancelTimelockedAction)]
+    fn cancel_timelocked_action(&self, action_id: u64) {
+        let action_mapper = self.timelocked_actions(action_id);
+        require!(!action_mapper.is_empty(), ACTION_NOT_FOUND_ERROR);
+
+        action_mapper.clear();
+        self.timelocked_action_cancelled_event(action_id);
+    }
+
+    fn queue_timelocked_action(&self, action: AdminAction<Self::Api>) -> u64 {
+        let action_id = self.last_timelocked_action_id().update(|id| {
+            *id += 1;
+            *id
+        });
+        let timelocked_action = TimelockedAction {
+            action,
+            execution_timestamp: self.blockchain().get_block_timestamp()
+                + self.timelock_delay_seconds().get(),
+        };
+        self.timelocked_action_queued_event(
+            action_id,
+            timelocked_action.execution_timestamp,
+            &timelocked_action.action,
+        );
+        self.timelocked_actions(action_id).set(timelocked_action);
+
+        action_id
+    }
+
+    /// Removes the action and returns it,
+    /// if its execution timestamp has passed and the grace period has not expired yet.
+    fn take_ready_timelocked_action(&self, action_id: u64) -> AdminAction<Self::Api> {
+        let action_mapper = self.timelocked_actions(action_id);
+        require!(!action_mapper.is_empty(), ACTION_NOT_FOUND_ERROR);
+
+        let timelocked_action = action_mapper.take();
+        let current_timestamp = self.blockchain().get_block_timestamp();
+        require!(
+            current_timestamp >= timelocked_action.execution_timestamp,
+            "timelock not expired"
+        );
+        require!(
+            current_timestamp
+                <= timelocked_action.execution_timestamp + TIMELOCK_GRACE_PERIOD_SECONDS,
+            "timelocked action is stale"
+        );
+
+        timelocked_action.action
+    }
+
+    #[view(getTimelockDelay)]
+    #[storage_mapper("timelock_delay_seconds")]
+    fn timelock_delay_seconds(&self) -> SingleValueMapper<u64>;
+
+    #[storage_mapper("last_timelocked_action_id")]
+    fn last_timelocked_action_id(&self) -> SingleValueMapper<u64>;
+
+    #[view(getTimelockedAction)]
+    #[storage_mapper("timelocked_actions")]
+    fn timelocked_actions(&self, action_id: u64) -> SingleValueMapper<TimelockedAction<Self::Api>>;
+}
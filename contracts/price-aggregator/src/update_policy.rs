@@ -0,0 +1,134 @@
+use multiversx_sc::imports::*;
+
+use crate::{
+    circuit_breaker::{self, DEVIATION_PRECISION},
+    events,
+    price_aggregator_data::{Role, TimestampedPrice, TokenPair, UpdatePolicy},
+    roles, statistics, storage, subscriptions,
+};
+
+#[multiversx_sc::module]
+pub trait UpdatePolicyModule:
+    circuit_breaker::CircuitBreakerModule
+    + statistics::StatisticsModule
+    + subscriptions::SubscriptionsModule
+    + storage::StorageModule
+    + roles::RolesModule
+    + multiversx_sc_modules::pause::PauseModule
+    + events::EventsModule
+{
+    /// A new round is needed when the price moved more than `deviation_threshold_bps`
+    /// from the last round, or when the last round is at least `heartbeat_seconds` old.
+    /// A value of 0 disables the respective condition, but at least one of them must be enabled.
+    /// When `enforced`, submissions starting a round that is not needed are rejected.
+    #[endpoint(setPairUpdatePolicy)]
+    fn set_pair_update_policy(
+        &self,
+        from: ManagedBuffer,
+        to: ManagedBuffer,
+        deviation_threshold_bps: u64,
+        heartbeat_seconds: u64,
+        enforced: bool,
+    ) {
+        self.require_role(Role::PairManager);
+        require!(
+            deviation_threshold_bps <= DEVIATION_PRECISION,
+            "invalid deviation threshold"
+        );
+        require!(
+            deviation_threshold_bps > 0 || heartbeat_seconds > 0,
+            "update policy must enable a condition"
+        );
+
+        self.pair_update_policy(&TokenPair { from, to })
+            .set(UpdatePolicy {
+                deviation_threshold_bps,
+                heartbeat_seconds,
+                enforced,
+            });
+    }
+
+    #[endpoint(clearPairUpdatePolicy)]
+    fn clear_pair_update_policy(&self, from: ManagedBuffer, to: ManagedBuffer) {
+        self.require_role(Role::PairManager);
+
+        self.pair_update_policy(&TokenPair { from, to }).clear();
+    }
+
+    /// Tells oracles whether submitting `candidate_price` would be useful.
+    /// Always true for pairs without an update policy or without rounds.
+    #[view(shouldUpdate)]
+    fn should_update(
+        &self,
+        from: ManagedBuffer,
+        to: ManagedBuffer,
+        candidate_price: BigUint,
+    ) -> bool {
+        self.is_update_needed(&TokenPair { from, to }, &candidate_price)
+    }
+
+    fn require_update_needed(&self, token_pair: &TokenPair<Self::Api>, price: &BigUint) {
+        let policy_mapper = self.pair_update_policy(token_pair);
+        if policy_mapper.is_empty() || !policy_mapper.get().enforced {
+            return;
+        }
+
+        require!(
+            self.is_update_needed(token_pair, price),
+            "update not needed"
+        );
+    }
+
+    fn is_update_needed(
+        &self,
+        token_pair: &TokenPair<Self::Api>,
+        candidate_price: &BigUint,
+    ) -> bool {
+        let policy_mapper = self.pair_update_policy(token_pair);
+        if policy_mapper.is_empty() {
+            return true;
+        }
+
+        let rounds = match self.rounds().get(token_pair) {
+            Some(rounds) if !rounds.is_empty() => rounds,
+            _ => return true,
+        };
+
+        let policy = policy_mapper.get();
+        let last_price = rounds.get(rounds.len());
+        let current_timestamp = self.blockchain().get_block_timestamp();
+        if policy.heartbeat_seconds > 0
+            && current_timestamp >= last_price.timestamp + policy.heartbeat_seconds
+        {
+            return true;
+        }
+
+        let candidate = TimestampedPrice {
+            price: candidate_price.clone(),
+            timestamp: current_timestamp,
+            decimals: last_price.decimals,
+        };
+        policy.deviation_threshold_bps > 0
+            && self.exceeds_deviation(&last_price, &candidate, policy.deviation_threshold_bps)
+    }
+
+    #[view(getPairUpdatePolicy)]
+    fn get_pair_update_policy(
+        &self,
+        from: ManagedBuffer,
+        to: ManagedBuffer,
+    ) -> OptionalValue<UpdatePolicy> {
+        let policy_mapper = self.pair_update_policy(&TokenPair { from, to });
+        if policy_mapper.is_empty() {
+            OptionalValue::None
+        } else {
+            OptionalValue::Some(policy_mapper.get())
+        }
+    }
+
+    #[storage_mapper("pair_update_policy")]
+    fn pair_update_policy(
+        &self,
+        token_pair: &TokenPair<Self::Api>,
+    ) -> SingleValueMapper<UpdatePolicy>;
+}
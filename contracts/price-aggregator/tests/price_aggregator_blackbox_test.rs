@@ -1,11 +1,20 @@
 use multiversx_price_aggregator_sc::{
-    price_aggregator_data::{OracleStatus, TimestampedPrice, TokenPair},
+    oracle_application::OracleApplicationModule,
+    pending_round::PendingRoundModule,
+    price_aggregator_data::{OracleStatus, PriceSource, TimestampedPrice, TokenPair},
+    staking::StakingModule,
+    statistics::StatisticsModule,
+    storage::StorageModule,
+    subscriptions::MAX_CONSECUTIVE_NOTIFICATION_FAILURES,
     ContractObj, PriceAggregator, MAX_ROUND_DURATION_SECONDS,
 };
 
 use multiversx_sc_scenario::imports::*;
 
 mod price_aggregator_proxy;
+mod price_consumer_mock;
+
+use price_consumer_mock::PriceConsumerMock;
 
 const DECIMALS: u8 = 0;
 const EGLD_TICKER: &[u8] = b"EGLD";
@@ -13,13 +22,19 @@ const NR_ORACLES: usize = 4;
 const SLASH_AMOUNT: u64 = 10;
 const SLASH_QUORUM: usize = 3;
 const STAKE_AMOUNT: u64 = 20;
+const STAKE_TOKEN_ID: TestTokenIdentifier = TestTokenIdentifier::new("STAKE-123456");
 const SUBMISSION_COUNT: usize = 3;
 const USD_TICKER: &[u8] = b"USDC";
 
 const PRICE_AGGREGATOR_ADDRESS: TestSCAddress = TestSCAddress::new("price-aggregator");
+const FALLBACK_PRICE_AGGREGATOR_ADDRESS: TestSCAddress =
+    TestSCAddress::new("price-aggregator-fallback");
+const CONSUMER_ADDRESS: TestSCAddress = TestSCAddress::new("consumer");
+const PRICE_CONSUMER_ADDRESS: TestAddress = TestAddress::new("price-consumer");
 const OWNER_ADDRESS: TestAddress = TestAddress::new("owner");
 const PRICE_AGGREGATOR_PATH: MxscPath =
     MxscPath::new("output/multiversx-price-aggregator-sc.mxsc.json");
+const PRICE_CONSUMER_MOCK_PATH: MxscPath = MxscPath::new("output/price-consumer-mock.mxsc.json");
 
 fn world() -> ScenarioWorld {
     let mut blockchain = ScenarioWorld::new();
@@ -28,6 +43,10 @@ fn world() -> ScenarioWorld {
         PRICE_AGGREGATOR_PATH,
         multiversx_price_aggregator_sc::ContractBuilder,
     );
+    blockchain.register_contract(
+        PRICE_CONSUMER_MOCK_PATH,
+        price_consumer_mock::ContractBuilder,
+    );
 
     blockchain
 }
@@ -138,6 +157,7 @@ impl PriceAggregatorTestState {
                 submission_timestamp,
                 price,
                 DECIMALS,
+                OptionalValue::<BigUint<StaticApi>>::None,
             )
             .run();
     }
@@ -160,6 +180,7 @@ impl PriceAggregatorTestState {
                 submission_timestamp,
                 price,
                 DECIMALS,
+                OptionalValue::<BigUint<StaticApi>>::None,
             )
             .with_result(ExpectStatus(4))
             .with_result(ExpectMessage(err_message))
@@ -234,7 +255,8 @@ fn test_price_aggregator_submit() {
                     .unwrap(),
                 OracleStatus {
                     total_submissions: 1,
-                    accepted_submissions: 1
+                    accepted_submissions: 1,
+                    replaced_submissions: 0
                 }
             );
         });
@@ -251,7 +273,8 @@ fn test_price_aggregator_submit() {
                     .unwrap(),
                 OracleStatus {
                     total_submissions: 2,
-                    accepted_submissions: 1
+                    accepted_submissions: 1,
+                    replaced_submissions: 0
                 }
             );
         });
@@ -385,3 +408,2410 @@ fn test_price_aggregator_slashing() {
         "only oracles allowed",
     );
 }
+
+#[test]
+fn test_price_aggregator_multi_token_staking() {
+    let mut state = PriceAggregatorTestState::new();
+    state.deploy();
+
+    // the second staking token counts double towards the required stake
+    state
+        .world
+        .tx()
+        .from(OWNER_ADDRESS)
+        .to(PRICE_AGGREGATOR_ADDRESS)
+        .typed(price_aggregator_proxy::PriceAggregatorProxy)
+        .set_staking_token_weight(STAKE_TOKEN_ID, 20_000u64)
+        .run();
+
+    let new_oracle = TestAddress::new("oracle5");
+    state
+        .world
+        .account(new_oracle)
+        .nonce(1)
+        .esdt_balance(STAKE_TOKEN_ID, STAKE_AMOUNT);
+
+    state
+        .world
+        .tx()
+        .from(OWNER_ADDRESS)
+        .to(PRICE_AGGREGATOR_ADDRESS)
+        .typed(price_aggregator_proxy::PriceAggregatorProxy)
+        .add_oracles(MultiValueVec::from(vec![new_oracle.to_address()]))
+        .run();
+
+    state
+        .world
+        .tx()
+        .from(new_oracle)
+        .to(PRICE_AGGREGATOR_ADDRESS)
+        .typed(price_aggregator_proxy::PriceAggregatorProxy)
+        .stake()
+        .single_esdt(&STAKE_TOKEN_ID.into(), 0, &BigUint::from(STAKE_AMOUNT / 2))
+        .run();
+
+    let staked_amount = state
+        .world
+        .query()
+        .to(PRICE_AGGREGATOR_ADDRESS)
+        .typed(price_aggregator_proxy::PriceAggregatorProxy)
+        .get_staked_amount(new_oracle)
+        .returns(ReturnsResultUnmanaged)
+        .run();
+    assert_eq!(staked_amount, RustBigUint::from(STAKE_AMOUNT));
+
+    let board_members = state
+        .world
+        .query()
+        .to(PRICE_AGGREGATOR_ADDRESS)
+        .typed(price_aggregator_proxy::PriceAggregatorProxy)
+        .get_board_members()
+        .returns(ReturnsResultUnmanaged)
+        .run();
+    assert_eq!(board_members.len(), NR_ORACLES + 1);
+
+    // unstaking any of the weighted stake leaves the oracle under the required amount
+    state
+        .world
+        .tx()
+        .from(new_oracle)
+        .to(PRICE_AGGREGATOR_ADDRESS)
+        .typed(price_aggregator_proxy::PriceAggregatorProxy)
+        .unstake(
+            1u64,
+            OptionalValue::Some(EgldOrEsdtTokenIdentifier::esdt(STAKE_TOKEN_ID)),
+        )
+        .with_result(ExpectError(4, "Not enough stake"))
+        .run();
+
+    // slashing takes the weighted slash amount out of the ESDT stake
+    for oracle in state.oracles.clone().iter().take(SLASH_QUORUM) {
+        state.vote_slash_member(oracle, new_oracle.to_address());
+    }
+    state
+        .world
+        .tx()
+        .from(&state.oracles[0])
+        .to(PRICE_AGGREGATOR_ADDRESS)
+        .typed(price_aggregator_proxy::PriceAggregatorProxy)
+        .slash_member(new_oracle)
+        .run();
+
+    state
+        .world
+        .query()
+        .to(PRICE_AGGREGATOR_ADDRESS)
+        .typed(price_aggregator_proxy::PriceAggregatorProxy)
+        .staked_token_amount(new_oracle, EgldOrEsdtTokenIdentifier::esdt(STAKE_TOKEN_ID))
+        .returns(ExpectValue(STAKE_AMOUNT / 2 - SLASH_AMOUNT / 2))
+        .run();
+}
+
+#[test]
+fn test_price_aggregator_key_rotation() {
+    let mut state = PriceAggregatorTestState::new();
+    state.deploy();
+    state.set_pair_decimals();
+    state.unpause_endpoint();
+
+    let old_oracle = state.oracles[0].clone();
+    let new_oracle = TestAddress::new("oracle-new-key");
+    state.world.account(new_oracle).nonce(1);
+
+    state.submit(&old_oracle, 95, 10_000);
+    state.vote_slash_member(&state.oracles[1].clone(), old_oracle.to_address());
+
+    state
+        .world
+        .tx()
+        .from(OWNER_ADDRESS)
+        .to(PRICE_AGGREGATOR_ADDRESS)
+        .typed(price_aggregator_proxy::PriceAggregatorProxy)
+        .set_governance_config(SLASH_QUORUM, 100u64)
+        .run();
+    let proposal_id = state
+        .world
+        .tx()
+        .from(&old_oracle)
+        .to(PRICE_AGGREGATOR_ADDRESS)
+        .typed(price_aggregator_proxy::PriceAggregatorProxy)
+        .propose_admin_action(price_aggregator_proxy::AdminAction::SetSubmissionCount {
+            submission_count: SUBMISSION_COUNT,
+        })
+        .returns(ReturnsResult)
+        .run();
+
+    state
+        .world
+        .tx()
+        .from(&old_oracle)
+        .to(PRICE_AGGREGATOR_ADDRESS)
+        .typed(price_aggregator_proxy::PriceAggregatorProxy)
+        .propose_key_rotation(new_oracle)
+        .run();
+
+    // only the proposed address can accept
+    state
+        .world
+        .tx()
+        .from(&state.oracles[1])
+        .to(PRICE_AGGREGATOR_ADDRESS)
+        .typed(price_aggregator_proxy::PriceAggregatorProxy)
+        .accept_key_rotation(old_oracle.to_address())
+        .with_result(ExpectError(4, "No key rotation proposed for caller"))
+        .run();
+
+    state
+        .world
+        .tx()
+        .from(new_oracle)
+        .to(PRICE_AGGREGATOR_ADDRESS)
+        .typed(price_aggregator_proxy::PriceAggregatorProxy)
+        .accept_key_rotation(old_oracle.to_address())
+        .run();
+
+    state
+        .world
+        .query()
+        .to(PRICE_AGGREGATOR_ADDRESS)
+        .typed(price_aggregator_proxy::PriceAggregatorProxy)
+        .get_staked_amount(new_oracle)
+        .returns(ExpectValue(STAKE_AMOUNT))
+        .run();
+
+    // the vote on the open proposal follows the key
+    state
+        .world
+        .query()
+        .to(PRICE_AGGREGATOR_ADDRESS)
+        .typed(price_aggregator_proxy::PriceAggregatorProxy)
+        .get_proposal_vote_count(proposal_id)
+        .returns(ExpectValue(1usize))
+        .run();
+
+    state
+        .world
+        .whitebox_query(&state.price_aggregator_whitebox, |sc| {
+            let old_address = managed_address!(&old_oracle.to_address());
+            let new_address = managed_address!(&new_oracle.to_address());
+            assert!(!sc.oracle_status().contains_key(&old_address));
+            assert_eq!(
+                sc.oracle_status().get(&new_address).unwrap(),
+                OracleStatus {
+                    total_submissions: 1,
+                    accepted_submissions: 1,
+                    replaced_submissions: 0
+                }
+            );
+            assert!(sc.is_staked_board_member(&new_address));
+            assert!(!sc.is_staked_board_member(&old_address));
+            assert_eq!(sc.slashing_proposal_voters(&new_address).len(), 1);
+
+            let token_pair = TokenPair {
+                from: managed_buffer!(EGLD_TICKER),
+                to: managed_buffer!(USD_TICKER),
+            };
+            let submissions = sc.submissions().get(&token_pair).unwrap();
+            assert_eq!(
+                submissions.get(&new_address).unwrap(),
+                managed_biguint!(10_000)
+            );
+        });
+
+    state.submit_and_expect_err(&old_oracle, 95, 10_000, "only oracles allowed");
+
+    // a proposal does not survive the removal of the oracle
+    let removed_oracle = state.oracles[1].clone();
+    let proposed_oracle = TestAddress::new("oracle-proposed-key");
+    state.world.account(proposed_oracle).nonce(1);
+    state
+        .world
+        .tx()
+        .from(&removed_oracle)
+        .to(PRICE_AGGREGATOR_ADDRESS)
+        .typed(price_aggregator_proxy::PriceAggregatorProxy)
+        .propose_key_rotation(proposed_oracle)
+        .run();
+    state
+        .world
+        .tx()
+        .from(OWNER_ADDRESS)
+        .to(PRICE_AGGREGATOR_ADDRESS)
+        .typed(price_aggregator_proxy::PriceAggregatorProxy)
+        .remove_oracles(
+            SUBMISSION_COUNT,
+            MultiValueVec::from(vec![removed_oracle.to_address()]),
+        )
+        .run();
+    state
+        .world
+        .tx()
+        .from(OWNER_ADDRESS)
+        .to(PRICE_AGGREGATOR_ADDRESS)
+        .typed(price_aggregator_proxy::PriceAggregatorProxy)
+        .add_oracles(MultiValueVec::from(vec![removed_oracle.to_address()]))
+        .run();
+    state
+        .world
+        .tx()
+        .from(proposed_oracle)
+        .to(PRICE_AGGREGATOR_ADDRESS)
+        .typed(price_aggregator_proxy::PriceAggregatorProxy)
+        .accept_key_rotation(removed_oracle.to_address())
+        .with_result(ExpectError(4, "No key rotation proposed for caller"))
+        .run();
+}
+
+#[test]
+fn test_price_aggregator_oracle_applications() {
+    let mut state = PriceAggregatorTestState::new();
+    state.deploy();
+
+    let approved_candidate = TestAddress::new("candidate1");
+    let rejected_candidate = TestAddress::new("candidate2");
+    for candidate in [approved_candidate, rejected_candidate] {
+        state
+            .world
+            .account(candidate)
+            .nonce(1)
+            .balance(STAKE_AMOUNT);
+    }
+
+    state
+        .world
+        .tx()
+        .from(approved_candidate)
+        .to(PRICE_AGGREGATOR_ADDRESS)
+        .typed(price_aggregator_proxy::PriceAggregatorProxy)
+        .apply_as_oracle("operator", "contact@operator")
+        .egld(STAKE_AMOUNT - 1)
+        .with_result(ExpectError(4, "Not enough stake"))
+        .run();
+
+    for candidate in [approved_candidate, rejected_candidate] {
+        state
+            .world
+            .tx()
+            .from(candidate)
+            .to(PRICE_AGGREGATOR_ADDRESS)
+            .typed(price_aggregator_proxy::PriceAggregatorProxy)
+            .apply_as_oracle("operator", "contact@operator")
+            .egld(STAKE_AMOUNT)
+            .run();
+    }
+
+    let applications = state
+        .world
+        .query()
+        .to(PRICE_AGGREGATOR_ADDRESS)
+        .typed(price_aggregator_proxy::PriceAggregatorProxy)
+        .get_oracle_applications()
+        .returns(ReturnsResult)
+        .run();
+    assert_eq!(applications.len(), 2);
+
+    // a board vote approves the first candidate
+    for oracle in state.oracles.clone().iter().take(SLASH_QUORUM) {
+        state
+            .world
+            .tx()
+            .from(oracle)
+            .to(PRICE_AGGREGATOR_ADDRESS)
+            .typed(price_aggregator_proxy::PriceAggregatorProxy)
+            .vote_oracle_application(approved_candidate, true)
+            .run();
+    }
+
+    // the owner rejects the second one
+    state
+        .world
+        .tx()
+        .from(OWNER_ADDRESS)
+        .to(PRICE_AGGREGATOR_ADDRESS)
+        .typed(price_aggregator_proxy::PriceAggregatorProxy)
+        .reject_oracle_application(rejected_candidate)
+        .run();
+
+    state
+        .world
+        .check_account(rejected_candidate)
+        .balance(STAKE_AMOUNT);
+
+    state
+        .world
+        .whitebox_query(&state.price_aggregator_whitebox, |sc| {
+            let approved_address = managed_address!(&approved_candidate.to_address());
+            let rejected_address = managed_address!(&rejected_candidate.to_address());
+            assert!(sc.oracle_status().contains_key(&approved_address));
+            assert!(sc.is_staked_board_member(&approved_address));
+            assert!(!sc.oracle_status().contains_key(&rejected_address));
+            assert!(sc.oracle_applications().is_empty());
+        });
+}
+
+#[test]
+fn test_price_aggregator_oracle_application_votes() {
+    let mut state = PriceAggregatorTestState::new();
+    state.deploy();
+
+    let candidate = TestAddress::new("candidate");
+    state
+        .world
+        .account(candidate)
+        .nonce(1)
+        .balance(STAKE_AMOUNT);
+    state
+        .world
+        .tx()
+        .from(candidate)
+        .to(PRICE_AGGREGATOR_ADDRESS)
+        .typed(price_aggregator_proxy::PriceAggregatorProxy)
+        .apply_as_oracle("operator", "contact@operator")
+        .egld(STAKE_AMOUNT)
+        .run();
+
+    let vote = |state: &mut PriceAggregatorTestState, voter: &AddressValue| {
+        state
+            .world
+            .tx()
+            .from(voter)
+            .to(PRICE_AGGREGATOR_ADDRESS)
+            .typed(price_aggregator_proxy::PriceAggregatorProxy)
+            .vote_oracle_application(candidate, true)
+            .run();
+    };
+    let expect_vote_count = |state: &mut PriceAggregatorTestState, expected: usize| {
+        state
+            .world
+            .query()
+            .to(PRICE_AGGREGATOR_ADDRESS)
+            .typed(price_aggregator_proxy::PriceAggregatorProxy)
+            .get_application_vote_count(candidate, true)
+            .returns(ExpectValue(expected))
+            .run();
+    };
+
+    // a rotated oracle cannot vote a second time with its new key
+    let rotated_oracle = state.oracles[0].clone();
+    let new_oracle = AddressValue::from(TestAddress::new("oracle-new-key"));
+    state.world.account(&new_oracle).nonce(1);
+    vote(&mut state, &rotated_oracle);
+    state
+        .world
+        .tx()
+        .from(&rotated_oracle)
+        .to(PRICE_AGGREGATOR_ADDRESS)
+        .typed(price_aggregator_proxy::PriceAggregatorProxy)
+        .propose_key_rotation(new_oracle.to_address())
+        .run();
+    state
+        .world
+        .tx()
+        .from(&new_oracle)
+        .to(PRICE_AGGREGATOR_ADDRESS)
+        .typed(price_aggregator_proxy::PriceAggregatorProxy)
+        .accept_key_rotation(rotated_oracle.to_address())
+        .run();
+    vote(&mut state, &new_oracle);
+    expect_vote_count(&mut state, 1);
+
+    // the vote of a removed oracle no longer counts
+    let removed_oracle = state.oracles[1].clone();
+    vote(&mut state, &removed_oracle);
+    expect_vote_count(&mut state, 2);
+    state
+        .world
+        .tx()
+        .from(OWNER_ADDRESS)
+        .to(PRICE_AGGREGATOR_ADDRESS)
+        .typed(price_aggregator_proxy::PriceAggregatorProxy)
+        .remove_oracles(
+            SUBMISSION_COUNT,
+            MultiValueVec::from(vec![removed_oracle.to_address()]),
+        )
+        .run();
+    expect_vote_count(&mut state, 1);
+
+    let oracle = state.oracles[2].clone();
+    vote(&mut state, &oracle);
+    state
+        .world
+        .whitebox_query(&state.price_aggregator_whitebox, |sc| {
+            assert!(sc
+                .oracle_applications()
+                .contains_key(&managed_address!(&candidate.to_address())));
+        });
+
+    let oracle = state.oracles[3].clone();
+    vote(&mut state, &oracle);
+    state
+        .world
+        .whitebox_query(&state.price_aggregator_whitebox, |sc| {
+            let candidate_address = managed_address!(&candidate.to_address());
+            assert!(sc.oracle_applications().is_empty());
+            assert!(sc.oracle_status().contains_key(&candidate_address));
+        });
+}
+
+#[test]
+fn test_price_aggregator_pair_oracles() {
+    let mut state = PriceAggregatorTestState::new();
+    state.deploy();
+    state.set_pair_decimals();
+    state.unpause_endpoint();
+
+    let pair_oracles = MultiValueVec::from(
+        state.oracles[..SUBMISSION_COUNT]
+            .iter()
+            .map(|oracle| oracle.to_address())
+            .collect::<Vec<_>>(),
+    );
+    state
+        .world
+        .tx()
+        .from(OWNER_ADDRESS)
+        .to(PRICE_AGGREGATOR_ADDRESS)
+        .typed(price_aggregator_proxy::PriceAggregatorProxy)
+        .set_pair_oracles(EGLD_TICKER, USD_TICKER, SUBMISSION_COUNT, pair_oracles)
+        .run();
+
+    state.submit_and_expect_err(
+        &state.oracles[NR_ORACLES - 1].clone(),
+        95,
+        10_000,
+        "oracle not allowed for this pair",
+    );
+
+    // removing an assigned oracle would leave the pair without a quorum
+    state
+        .world
+        .tx()
+        .from(OWNER_ADDRESS)
+        .to(PRICE_AGGREGATOR_ADDRESS)
+        .typed(price_aggregator_proxy::PriceAggregatorProxy)
+        .remove_oracles(
+            SUBMISSION_COUNT,
+            MultiValueVec::from(vec![state.oracles[0].to_address()]),
+        )
+        .with_result(ExpectError(4, "Invalid pair submission count"))
+        .run();
+
+    for oracle in state.oracles[..SUBMISSION_COUNT].to_vec().iter() {
+        state.submit(oracle, 100, 10_000);
+    }
+
+    state
+        .world
+        .whitebox_query(&state.price_aggregator_whitebox, |sc| {
+            let token_pair = TokenPair {
+                from: managed_buffer!(EGLD_TICKER),
+                to: managed_buffer!(USD_TICKER),
+            };
+            assert_eq!(sc.rounds().get(&token_pair).unwrap().len(), 1);
+        });
+}
+
+#[test]
+fn test_price_aggregator_board_governance() {
+    let mut state = PriceAggregatorTestState::new();
+    state.deploy();
+
+    let voting_period_seconds = 100u64;
+    state
+        .world
+        .tx()
+        .from(OWNER_ADDRESS)
+        .to(PRICE_AGGREGATOR_ADDRESS)
+        .typed(price_aggregator_proxy::PriceAggregatorProxy)
+        .set_governance_config(SLASH_QUORUM, voting_period_seconds)
+        .run();
+
+    let new_oracle = TestAddress::new("oracle5");
+    let add_oracle_proposal_id = state
+        .world
+        .tx()
+        .from(&state.oracles[0])
+        .to(PRICE_AGGREGATOR_ADDRESS)
+        .typed(price_aggregator_proxy::PriceAggregatorProxy)
+        .propose_admin_action(price_aggregator_proxy::AdminAction::AddOracles {
+            oracles: ManagedVec::from_single_item(new_oracle.to_managed_address()),
+        })
+        .returns(ReturnsResult)
+        .run();
+
+    state
+        .world
+        .tx()
+        .from(&state.oracles[1])
+        .to(PRICE_AGGREGATOR_ADDRESS)
+        .typed(price_aggregator_proxy::PriceAggregatorProxy)
+        .execute_admin_action(add_oracle_proposal_id)
+        .with_result(ExpectError(4, "Quorum not reached"))
+        .run();
+
+    for oracle in state.oracles[1..SLASH_QUORUM].to_vec().iter() {
+        state
+            .world
+            .tx()
+            .from(oracle)
+            .to(PRICE_AGGREGATOR_ADDRESS)
+            .typed(price_aggregator_proxy::PriceAggregatorProxy)
+            .vote_admin_action(add_oracle_proposal_id)
+            .run();
+    }
+
+    state
+        .world
+        .tx()
+        .from(&state.oracles[1])
+        .to(PRICE_AGGREGATOR_ADDRESS)
+        .typed(price_aggregator_proxy::PriceAggregatorProxy)
+        .execute_admin_action(add_oracle_proposal_id)
+        .run();
+
+    state
+        .world
+        .whitebox_query(&state.price_aggregator_whitebox, |sc| {
+            assert!(sc
+                .oracle_status()
+                .contains_key(&managed_address!(&new_oracle.to_address())));
+        });
+
+    // proposals cannot be executed after the deadline
+    let submission_count_proposal_id = state
+        .world
+        .tx()
+        .from(&state.oracles[0])
+        .to(PRICE_AGGREGATOR_ADDRESS)
+        .typed(price_aggregator_proxy::PriceAggregatorProxy)
+        .propose_admin_action(price_aggregator_proxy::AdminAction::SetSubmissionCount {
+            submission_count: SUBMISSION_COUNT + 1,
+        })
+        .returns(ReturnsResult)
+        .run();
+
+    for oracle in state.oracles[1..SLASH_QUORUM].to_vec().iter() {
+        state
+            .world
+            .tx()
+            .from(oracle)
+            .to(PRICE_AGGREGATOR_ADDRESS)
+            .typed(price_aggregator_proxy::PriceAggregatorProxy)
+            .vote_admin_action(submission_count_proposal_id)
+            .run();
+    }
+
+    state
+        .world
+        .current_block()
+        .block_timestamp(100 + voting_period_seconds + 1);
+
+    state
+        .world
+        .tx()
+        .from(&state.oracles[0])
+        .to(PRICE_AGGREGATOR_ADDRESS)
+        .typed(price_aggregator_proxy::PriceAggregatorProxy)
+        .execute_admin_action(submission_count_proposal_id)
+        .with_result(ExpectError(4, "proposal expired"))
+        .run();
+}
+
+#[test]
+fn test_price_aggregator_timelock() {
+    let mut state = PriceAggregatorTestState::new();
+    state.deploy();
+
+    let delay_seconds = 100u64;
+    state
+        .world
+        .tx()
+        .from(OWNER_ADDRESS)
+        .to(PRICE_AGGREGATOR_ADDRESS)
+        .typed(price_aggregator_proxy::PriceAggregatorProxy)
+        .set_timelock_delay(delay_seconds)
+        .run();
+
+    // owner calls are queued instead of being applied immediately
+    state
+        .world
+        .tx()
+        .from(OWNER_ADDRESS)
+        .to(PRICE_AGGREGATOR_ADDRESS)
+        .typed(price_aggregator_proxy::PriceAggregatorProxy)
+        .set_submission_count(SUBMISSION_COUNT + 1)
+        .run();
+    state
+        .world
+        .tx()
+        .from(OWNER_ADDRESS)
+        .to(PRICE_AGGREGATOR_ADDRESS)
+        .typed(price_aggregator_proxy::PriceAggregatorProxy)
+        .change_amounts(STAKE_AMOUNT / 2, SLASH_AMOUNT / 2)
+        .run();
+    state
+        .world
+        .tx()
+        .from(OWNER_ADDRESS)
+        .to(PRICE_AGGREGATOR_ADDRESS)
+        .typed(price_aggregator_proxy::PriceAggregatorProxy)
+        .set_staking_token_weight(EgldOrEsdtTokenIdentifier::egld(), 1u64)
+        .run();
+
+    state
+        .world
+        .query()
+        .to(PRICE_AGGREGATOR_ADDRESS)
+        .typed(price_aggregator_proxy::PriceAggregatorProxy)
+        .submission_count()
+        .returns(ExpectValue(SUBMISSION_COUNT))
+        .run();
+    state
+        .world
+        .query()
+        .to(PRICE_AGGREGATOR_ADDRESS)
+        .typed(price_aggregator_proxy::PriceAggregatorProxy)
+        .get_staked_amount(state.oracles[0].to_address())
+        .returns(ExpectValue(STAKE_AMOUNT))
+        .run();
+
+    state
+        .world
+        .tx()
+        .from(&state.oracles[0])
+        .to(PRICE_AGGREGATOR_ADDRESS)
+        .typed(price_aggregator_proxy::PriceAggregatorProxy)
+        .execute_timelocked_action(1u64)
+        .with_result(ExpectError(4, "timelock not expired"))
+        .run();
+
+    state
+        .world
+        .tx()
+        .from(OWNER_ADDRESS)
+        .to(PRICE_AGGREGATOR_ADDRESS)
+        .typed(price_aggregator_proxy::PriceAggregatorProxy)
+        .cancel_timelocked_action(2u64)
+        .run();
+
+    state
+        .world
+        .current_block()
+        .block_timestamp(100 + delay_seconds);
+
+    state
+        .world
+        .tx()
+        .from(&state.oracles[0])
+        .to(PRICE_AGGREGATOR_ADDRESS)
+        .typed(price_aggregator_proxy::PriceAggregatorProxy)
+        .execute_timelocked_action(1u64)
+        .run();
+    state
+        .world
+        .tx()
+        .from(&state.oracles[0])
+        .to(PRICE_AGGREGATOR_ADDRESS)
+        .typed(price_aggregator_proxy::PriceAggregatorProxy)
+        .execute_timelocked_action(2u64)
+        .with_result(ExpectError(4, "timelocked action not found"))
+        .run();
+    state
+        .world
+        .tx()
+        .from(&state.oracles[0])
+        .to(PRICE_AGGREGATOR_ADDRESS)
+        .typed(price_aggregator_proxy::PriceAggregatorProxy)
+        .execute_timelocked_action(3u64)
+        .run();
+    state
+        .world
+        .query()
+        .to(PRICE_AGGREGATOR_ADDRESS)
+        .typed(price_aggregator_proxy::PriceAggregatorProxy)
+        .get_staked_amount(state.oracles[0].to_address())
+        .returns(ExpectValue(0u64))
+        .run();
+
+    state
+        .world
+        .query()
+        .to(PRICE_AGGREGATOR_ADDRESS)
+        .typed(price_aggregator_proxy::PriceAggregatorProxy)
+        .submission_count()
+        .returns(ExpectValue(SUBMISSION_COUNT + 1))
+        .run();
+    state
+        .world
+        .whitebox_query(&state.price_aggregator_whitebox, |sc| {
+            assert_eq!(
+                sc.required_stake_amount().get(),
+                managed_biguint!(STAKE_AMOUNT)
+            );
+        });
+}
+
+#[test]
+fn test_price_aggregator_roles() {
+    let mut state = PriceAggregatorTestState::new();
+    state.deploy();
+    state.unpause_endpoint();
+
+    let role_admin = TestAddress::new("role-admin");
+    let pauser = TestAddress::new("pauser");
+    let oracle_manager = TestAddress::new("oracle-manager");
+    for address in [role_admin, pauser, oracle_manager] {
+        state.world.account(address).nonce(1);
+    }
+
+    state
+        .world
+        .tx()
+        .from(OWNER_ADDRESS)
+        .to(PRICE_AGGREGATOR_ADDRESS)
+        .typed(price_aggregator_proxy::PriceAggregatorProxy)
+        .grant_role(price_aggregator_proxy::Role::RoleAdmin, role_admin)
+        .run();
+
+    for (role, address) in [
+        (price_aggregator_proxy::Role::Pauser, pauser),
+        (price_aggregator_proxy::Role::OracleManager, oracle_manager),
+    ] {
+        state
+            .world
+            .tx()
+            .from(role_admin)
+            .to(PRICE_AGGREGATOR_ADDRESS)
+            .typed(price_aggregator_proxy::PriceAggregatorProxy)
+            .grant_role(role, address)
+            .run();
+    }
+
+    state
+        .world
+        .tx()
+        .from(role_admin)
+        .to(PRICE_AGGREGATOR_ADDRESS)
+        .typed(price_aggregator_proxy::PriceAggregatorProxy)
+        .grant_role(price_aggregator_proxy::Role::RoleAdmin, pauser)
+        .with_result(ExpectError(4, "only owner can manage role admins"))
+        .run();
+
+    // the pauser can pause, but not change the pair configuration
+    state
+        .world
+        .tx()
+        .from(pauser)
+        .to(PRICE_AGGREGATOR_ADDRESS)
+        .typed(price_aggregator_proxy::PriceAggregatorProxy)
+        .emergency_pause()
+        .run();
+    state
+        .world
+        .query()
+        .to(PRICE_AGGREGATOR_ADDRESS)
+        .typed(price_aggregator_proxy::PriceAggregatorProxy)
+        .paused_status()
+        .returns(ExpectValue(true))
+        .run();
+    state
+        .world
+        .tx()
+        .from(pauser)
+        .to(PRICE_AGGREGATOR_ADDRESS)
+        .typed(price_aggregator_proxy::PriceAggregatorProxy)
+        .set_pair_decimals(EGLD_TICKER, USD_TICKER, DECIMALS)
+        .with_result(ExpectError(4, "caller does not have the required role"))
+        .run();
+
+    state
+        .world
+        .tx()
+        .from(oracle_manager)
+        .to(PRICE_AGGREGATOR_ADDRESS)
+        .typed(price_aggregator_proxy::PriceAggregatorProxy)
+        .set_submission_count(SUBMISSION_COUNT + 1)
+        .run();
+    state
+        .world
+        .query()
+        .to(PRICE_AGGREGATOR_ADDRESS)
+        .typed(price_aggregator_proxy::PriceAggregatorProxy)
+        .submission_count()
+        .returns(ExpectValue(SUBMISSION_COUNT + 1))
+        .run();
+
+    state
+        .world
+        .tx()
+        .from(role_admin)
+        .to(PRICE_AGGREGATOR_ADDRESS)
+        .typed(price_aggregator_proxy::PriceAggregatorProxy)
+        .revoke_role(price_aggregator_proxy::Role::OracleManager, oracle_manager)
+        .run();
+    state
+        .world
+        .tx()
+        .from(oracle_manager)
+        .to(PRICE_AGGREGATOR_ADDRESS)
+        .typed(price_aggregator_proxy::PriceAggregatorProxy)
+        .set_submission_count(SUBMISSION_COUNT)
+        .with_result(ExpectError(4, "caller does not have the required role"))
+        .run();
+}
+
+#[test]
+fn test_price_aggregator_circuit_breaker() {
+    let mut state = PriceAggregatorTestState::new();
+    state.deploy();
+    state.set_pair_decimals();
+    state.unpause_endpoint();
+
+    // hold rounds moving more than 10%, confirmable for 60 seconds
+    state
+        .world
+        .tx()
+        .from(OWNER_ADDRESS)
+        .to(PRICE_AGGREGATOR_ADDRESS)
+        .typed(price_aggregator_proxy::PriceAggregatorProxy)
+        .set_circuit_breaker(1_000u64, 60u64)
+        .run();
+
+    let submit_round = |state: &mut PriceAggregatorTestState, timestamp: u64, price: u64| {
+        state.world.current_block().block_timestamp(timestamp);
+        for oracle_index in 0..SUBMISSION_COUNT {
+            let oracle = state.oracles[oracle_index].clone();
+            state.submit(&oracle, timestamp, price);
+        }
+    };
+    let latest_price = |state: &mut PriceAggregatorTestState| {
+        let mut result = (0u32, 0u64);
+        state
+            .world
+            .whitebox_query(&state.price_aggregator_whitebox, |sc| {
+                let (round_id, _, _, _, price, _) = sc
+                    .latest_price_feed(managed_buffer!(EGLD_TICKER), managed_buffer!(USD_TICKER))
+                    .into_tuple();
+                result = (round_id, price.to_u64().unwrap());
+            });
+        result
+    };
+
+    submit_round(&mut state, 100, 10_000);
+    assert_eq!(latest_price(&mut state), (1, 10_000));
+
+    // a flash crash is held instead of published
+    submit_round(&mut state, 110, 5_000);
+    assert_eq!(latest_price(&mut state), (1, 10_000));
+    state
+        .world
+        .query()
+        .to(PRICE_AGGREGATOR_ADDRESS)
+        .typed(price_aggregator_proxy::PriceAggregatorProxy)
+        .get_held_price(EGLD_TICKER, USD_TICKER)
+        .returns(ExpectValue(OptionalValue::Some(
+            price_aggregator_proxy::TimestampedPrice {
+                price: BigUint::from(5_000u64),
+                timestamp: 110,
+                decimals: DECIMALS,
+            },
+        )))
+        .run();
+
+    // a second round close to the held price confirms the move
+    submit_round(&mut state, 150, 5_100);
+    assert_eq!(latest_price(&mut state), (2, 5_100));
+    state
+        .world
+        .query()
+        .to(PRICE_AGGREGATOR_ADDRESS)
+        .typed(price_aggregator_proxy::PriceAggregatorProxy)
+        .get_held_price(EGLD_TICKER, USD_TICKER)
+        .returns(ExpectValue(
+            OptionalValue::<price_aggregator_proxy::TimestampedPrice<StaticApi>>::None,
+        ))
+        .run();
+
+    // a held price can also be released by the owner
+    submit_round(&mut state, 160, 10_000);
+    assert_eq!(latest_price(&mut state), (2, 5_100));
+    state
+        .world
+        .tx()
+        .from(&state.oracles[0])
+        .to(PRICE_AGGREGATOR_ADDRESS)
+        .typed(price_aggregator_proxy::PriceAggregatorProxy)
+        .release_held_price(EGLD_TICKER, USD_TICKER)
+        .with_result(ExpectError(4, "Endpoint can only be called by owner"))
+        .run();
+    state
+        .world
+        .tx()
+        .from(OWNER_ADDRESS)
+        .to(PRICE_AGGREGATOR_ADDRESS)
+        .typed(price_aggregator_proxy::PriceAggregatorProxy)
+        .release_held_price(EGLD_TICKER, USD_TICKER)
+        .run();
+    assert_eq!(latest_price(&mut state), (3, 10_000));
+}
+
+#[test]
+fn test_price_aggregator_manual_price() {
+    let mut state = PriceAggregatorTestState::new();
+    state.deploy();
+    state.set_pair_decimals();
+    state.unpause_endpoint();
+
+    state
+        .world
+        .tx()
+        .from(&state.oracles[0])
+        .to(PRICE_AGGREGATOR_ADDRESS)
+        .typed(price_aggregator_proxy::PriceAggregatorProxy)
+        .set_manual_price(EGLD_TICKER, USD_TICKER, 12_000u64, 200u64)
+        .with_result(ExpectError(4, "caller does not have the required role"))
+        .run();
+    state
+        .world
+        .tx()
+        .from(OWNER_ADDRESS)
+        .to(PRICE_AGGREGATOR_ADDRESS)
+        .typed(price_aggregator_proxy::PriceAggregatorProxy)
+        .set_manual_price(EGLD_TICKER, USD_TICKER, 12_000u64, 200u64)
+        .run();
+
+    let latest_price = |state: &mut PriceAggregatorTestState| {
+        let mut result = (0u32, 0u64);
+        state
+            .world
+            .whitebox_query(&state.price_aggregator_whitebox, |sc| {
+                let (round_id, _, _, _, price, _) = sc
+                    .latest_price_feed(managed_buffer!(EGLD_TICKER), managed_buffer!(USD_TICKER))
+                    .into_tuple();
+                result = (round_id, price.to_u64().unwrap());
+            });
+        result
+    };
+    let expect_price_source =
+        |state: &mut PriceAggregatorTestState, source: price_aggregator_proxy::PriceSource| {
+            state
+                .world
+                .query()
+                .to(PRICE_AGGREGATOR_ADDRESS)
+                .typed(price_aggregator_proxy::PriceAggregatorProxy)
+                .latest_price_source(EGLD_TICKER, USD_TICKER)
+                .returns(ExpectValue(source))
+                .run();
+        };
+
+    // the manual price is served even before the first round
+    assert_eq!(latest_price(&mut state), (0, 12_000));
+    expect_price_source(&mut state, price_aggregator_proxy::PriceSource::Manual);
+    state
+        .world
+        .whitebox_query(&state.price_aggregator_whitebox, |sc| {
+            let (_, _, _, _, price, _, source) = sc
+                .latest_price_feed_with_source(
+                    managed_buffer!(EGLD_TICKER),
+                    managed_buffer!(USD_TICKER),
+                )
+                .into_tuple();
+            assert_eq!(price, managed_biguint!(12_000));
+            assert_eq!(source, PriceSource::Manual);
+        });
+
+    // and stops being served once expired
+    state.world.current_block().block_timestamp(200);
+    expect_price_source(&mut state, price_aggregator_proxy::PriceSource::Oracles);
+    state
+        .world
+        .query()
+        .to(PRICE_AGGREGATOR_ADDRESS)
+        .typed(price_aggregator_proxy::PriceAggregatorProxy)
+        .latest_price_feed(EGLD_TICKER, USD_TICKER)
+        .returns(ExpectError(4, "token pair not found"))
+        .run();
+
+    // a new round replaces the manual price
+    state
+        .world
+        .tx()
+        .from(OWNER_ADDRESS)
+        .to(PRICE_AGGREGATOR_ADDRESS)
+        .typed(price_aggregator_proxy::PriceAggregatorProxy)
+        .set_manual_price(EGLD_TICKER, USD_TICKER, 12_000u64, 500u64)
+        .run();
+    assert_eq!(latest_price(&mut state), (0, 12_000));
+
+    for oracle_index in 0..SUBMISSION_COUNT {
+        let oracle = state.oracles[oracle_index].clone();
+        state.submit(&oracle, 200, 10_000);
+    }
+    assert_eq!(latest_price(&mut state), (1, 10_000));
+    expect_price_source(&mut state, price_aggregator_proxy::PriceSource::Oracles);
+}
+
+#[test]
+fn test_price_aggregator_fallback() {
+    let mut state = PriceAggregatorTestState::new();
+    state.deploy();
+
+    // a second aggregator, serving a manual price
+    state
+        .world
+        .new_address(OWNER_ADDRESS, 2, FALLBACK_PRICE_AGGREGATOR_ADDRESS);
+    state
+        .world
+        .tx()
+        .from(OWNER_ADDRESS)
+        .typed(price_aggregator_proxy::PriceAggregatorProxy)
+        .init(
+            EgldOrEsdtTokenIdentifier::egld(),
+            STAKE_AMOUNT,
+            SLASH_AMOUNT,
+            SLASH_QUORUM,
+            SUBMISSION_COUNT,
+            MultiValueVec::from(
+                state
+                    .oracles
+                    .iter()
+                    .map(|oracle| oracle.to_address())
+                    .collect::<Vec<_>>(),
+            ),
+        )
+        .code(PRICE_AGGREGATOR_PATH)
+        .run();
+    state
+        .world
+        .tx()
+        .from(OWNER_ADDRESS)
+        .to(FALLBACK_PRICE_AGGREGATOR_ADDRESS)
+        .typed(price_aggregator_proxy::PriceAggregatorProxy)
+        .set_pair_decimals(EGLD_TICKER, USD_TICKER, DECIMALS)
+        .run();
+    state
+        .world
+        .tx()
+        .from(OWNER_ADDRESS)
+        .to(FALLBACK_PRICE_AGGREGATOR_ADDRESS)
+        .typed(price_aggregator_proxy::PriceAggregatorProxy)
+        .set_manual_price(EGLD_TICKER, USD_TICKER, 11_000u64, 10_000u64)
+        .run();
+    state
+        .world
+        .tx()
+        .from(OWNER_ADDRESS)
+        .to(FALLBACK_PRICE_AGGREGATOR_ADDRESS)
+        .typed(price_aggregator_proxy::PriceAggregatorProxy)
+        .unpause_endpoint()
+        .run();
+
+    state.set_pair_decimals();
+    state.unpause_endpoint();
+    for oracle_index in 0..SUBMISSION_COUNT {
+        let oracle = state.oracles[oracle_index].clone();
+        state.submit(&oracle, 100, 10_000);
+    }
+
+    state
+        .world
+        .tx()
+        .from(OWNER_ADDRESS)
+        .to(PRICE_AGGREGATOR_ADDRESS)
+        .typed(price_aggregator_proxy::PriceAggregatorProxy)
+        .set_pair_fallback(EGLD_TICKER, USD_TICKER, OWNER_ADDRESS, 60u64)
+        .with_result(ExpectError(4, "fallback must be a smart contract"))
+        .run();
+    state
+        .world
+        .tx()
+        .from(OWNER_ADDRESS)
+        .to(PRICE_AGGREGATOR_ADDRESS)
+        .typed(price_aggregator_proxy::PriceAggregatorProxy)
+        .set_pair_fallback(
+            EGLD_TICKER,
+            USD_TICKER,
+            FALLBACK_PRICE_AGGREGATOR_ADDRESS,
+            60u64,
+        )
+        .run();
+
+    let latest_price = |state: &mut PriceAggregatorTestState| {
+        let (round_id, _, _, _, price, _, source) = state
+            .world
+            .query()
+            .to(PRICE_AGGREGATOR_ADDRESS)
+            .typed(price_aggregator_proxy::PriceAggregatorProxy)
+            .latest_price_feed_with_fallback(EGLD_TICKER, USD_TICKER)
+            .returns(ReturnsResult)
+            .run()
+            .into_tuple();
+        (round_id, price.to_u64().unwrap(), source)
+    };
+
+    assert_eq!(
+        latest_price(&mut state),
+        (1, 10_000, price_aggregator_proxy::PriceSource::Oracles)
+    );
+
+    // the fallback covers for a stale pair
+    state.world.current_block().block_timestamp(161);
+    assert_eq!(
+        latest_price(&mut state),
+        (0, 11_000, price_aggregator_proxy::PriceSource::Fallback)
+    );
+
+    // and for a paused contract
+    state.world.current_block().block_timestamp(120);
+    assert_eq!(
+        latest_price(&mut state),
+        (1, 10_000, price_aggregator_proxy::PriceSource::Oracles)
+    );
+    state
+        .world
+        .tx()
+        .from(OWNER_ADDRESS)
+        .to(PRICE_AGGREGATOR_ADDRESS)
+        .typed(price_aggregator_proxy::PriceAggregatorProxy)
+        .pause_endpoint()
+        .run();
+    assert_eq!(
+        latest_price(&mut state),
+        (0, 11_000, price_aggregator_proxy::PriceSource::Fallback)
+    );
+}
+
+#[test]
+fn test_price_aggregator_v3_views() {
+    let mut state = PriceAggregatorTestState::new();
+    state.deploy();
+    state.set_pair_decimals();
+    state.unpause_endpoint();
+
+    for (timestamp, price) in [(100, 10_000), (110, 11_000)] {
+        state.world.current_block().block_timestamp(timestamp);
+        for oracle_index in 0..SUBMISSION_COUNT {
+            let oracle = state.oracles[oracle_index].clone();
+            state.submit(&oracle, timestamp, price);
+        }
+    }
+
+    let round_data = state
+        .world
+        .query()
+        .to(PRICE_AGGREGATOR_ADDRESS)
+        .typed(price_aggregator_proxy::PriceAggregatorProxy)
+        .latest_round_data_v3(EGLD_TICKER, USD_TICKER)
+        .returns(ReturnsResult)
+        .run()
+        .into_tuple();
+    assert_eq!(round_data, (2, BigUint::from(11_000u64), 110, 110, 2));
+
+    let round_data = state
+        .world
+        .query()
+        .to(PRICE_AGGREGATOR_ADDRESS)
+        .typed(price_aggregator_proxy::PriceAggregatorProxy)
+        .get_round_data(EGLD_TICKER, USD_TICKER, 1u32)
+        .returns(ReturnsResult)
+        .run()
+        .into_tuple();
+    assert_eq!(round_data, (1, BigUint::from(10_000u64), 100, 100, 1));
+
+    state
+        .world
+        .query()
+        .to(PRICE_AGGREGATOR_ADDRESS)
+        .typed(price_aggregator_proxy::PriceAggregatorProxy)
+        .get_round_data(EGLD_TICKER, USD_TICKER, 3u32)
+        .returns(ExpectError(4, "round not found"))
+        .run();
+
+    state
+        .world
+        .query()
+        .to(PRICE_AGGREGATOR_ADDRESS)
+        .typed(price_aggregator_proxy::PriceAggregatorProxy)
+        .decimals(EGLD_TICKER, USD_TICKER)
+        .returns(ExpectValue(DECIMALS))
+        .run();
+    state
+        .world
+        .query()
+        .to(PRICE_AGGREGATOR_ADDRESS)
+        .typed(price_aggregator_proxy::PriceAggregatorProxy)
+        .description(EGLD_TICKER, USD_TICKER)
+        .returns(ExpectValue(ManagedBuffer::from("EGLD / USDC")))
+        .run();
+    state
+        .world
+        .query()
+        .to(PRICE_AGGREGATOR_ADDRESS)
+        .typed(price_aggregator_proxy::PriceAggregatorProxy)
+        .version()
+        .returns(ExpectValue(1u64))
+        .run();
+}
+
+#[test]
+fn test_price_aggregator_batch_queries() {
+    let mut state = PriceAggregatorTestState::new();
+    state.deploy();
+    state.set_pair_decimals();
+    state.unpause_endpoint();
+
+    // unlike latestRoundData, pages are empty before the first round
+    state
+        .world
+        .whitebox_query(&state.price_aggregator_whitebox, |sc| {
+            assert_eq!(sc.latest_round_data_paginated(0, 10).len(), 0);
+        });
+
+    for oracle_index in 0..SUBMISSION_COUNT {
+        let oracle = state.oracles[oracle_index].clone();
+        state.submit(&oracle, 100, 10_000);
+    }
+
+    state
+        .world
+        .whitebox_query(&state.price_aggregator_whitebox, |sc| {
+            assert_eq!(sc.latest_round_data_paginated(0, 10).len(), 1);
+            assert_eq!(sc.latest_round_data_paginated(1, 10).len(), 0);
+
+            let mut pairs = MultiValueEncoded::new();
+            pairs.push((managed_buffer!(EGLD_TICKER), managed_buffer!(USD_TICKER)).into());
+            pairs.push((managed_buffer!(EGLD_TICKER), managed_buffer!(b"EUR")).into());
+            let price_feeds = sc.latest_price_feeds(pairs).into_iter().collect::<Vec<_>>();
+            assert_eq!(price_feeds.len(), 2);
+
+            let price_feed = price_feeds[0].as_ref().unwrap();
+            assert_eq!(price_feed.round_id, 1);
+            assert_eq!(price_feed.price, managed_biguint!(10_000));
+            assert!(price_feeds[1].is_none());
+        });
+}
+
+#[test]
+fn test_price_aggregator_pending_round() {
+    let mut state = PriceAggregatorTestState::new();
+    state.deploy();
+    state.set_pair_decimals();
+    state.unpause_endpoint();
+
+    state.submit(&state.oracles[0].clone(), 100, 10_000);
+    state.submit(&state.oracles[1].clone(), 100, 11_000);
+    state.world.current_block().block_timestamp(110);
+
+    let oracles = state.oracles.clone();
+    state
+        .world
+        .whitebox_query(&state.price_aggregator_whitebox, |sc| {
+            let pending_round =
+                sc.get_pending_round(managed_buffer!(EGLD_TICKER), managed_buffer!(USD_TICKER));
+            assert_eq!(pending_round.submission_count, 2);
+            assert_eq!(pending_round.first_submission_timestamp, 100);
+            assert_eq!(pending_round.last_submission_timestamp, 100);
+            assert_eq!(
+                pending_round.seconds_until_discarded,
+                MAX_ROUND_DURATION_SECONDS - 10
+            );
+            assert_eq!(pending_round.oracles.len(), 2);
+            assert_eq!(pending_round.median, Some(managed_biguint!(10_500)));
+
+            assert!(sc.has_submitted(
+                managed_buffer!(EGLD_TICKER),
+                managed_buffer!(USD_TICKER),
+                managed_address!(&oracles[0].to_address())
+            ));
+            assert!(!sc.has_submitted(
+                managed_buffer!(EGLD_TICKER),
+                managed_buffer!(USD_TICKER),
+                managed_address!(&oracles[2].to_address())
+            ));
+
+            let empty_round =
+                sc.get_pending_round(managed_buffer!(EGLD_TICKER), managed_buffer!(b"EUR"));
+            assert_eq!(empty_round.submission_count, 0);
+            assert_eq!(empty_round.median, None);
+        });
+}
+
+#[test]
+fn test_price_aggregator_submission_updates() {
+    let mut state = PriceAggregatorTestState::new();
+    state.deploy();
+    state.set_pair_decimals();
+    state.unpause_endpoint();
+
+    state
+        .world
+        .tx()
+        .from(OWNER_ADDRESS)
+        .to(PRICE_AGGREGATOR_ADDRESS)
+        .typed(price_aggregator_proxy::PriceAggregatorProxy)
+        .set_submission_updates_enabled(true)
+        .run();
+
+    // the second submission replaces the first one
+    state.submit(&state.oracles[0].clone(), 100, 10_000);
+    state.submit(&state.oracles[0].clone(), 100, 12_000);
+
+    let oracles = state.oracles.clone();
+    state
+        .world
+        .whitebox_query(&state.price_aggregator_whitebox, |sc| {
+            let pending_round =
+                sc.get_pending_round(managed_buffer!(EGLD_TICKER), managed_buffer!(USD_TICKER));
+            assert_eq!(pending_round.submission_count, 1);
+            assert_eq!(pending_round.median, Some(managed_biguint!(12_000)));
+
+            assert_eq!(
+                sc.oracle_status()
+                    .get(&managed_address!(&oracles[0].to_address()))
+                    .unwrap(),
+                OracleStatus {
+                    accepted_submissions: 1,
+                    total_submissions: 2,
+                    replaced_submissions: 1
+                }
+            );
+        });
+
+    state.submit(&state.oracles[1].clone(), 100, 11_000);
+    state.submit(&state.oracles[2].clone(), 100, 13_000);
+
+    state
+        .world
+        .whitebox_query(&state.price_aggregator_whitebox, |sc| {
+            let (round_id, _, _, _, price, _) = sc
+                .latest_price_feed(managed_buffer!(EGLD_TICKER), managed_buffer!(USD_TICKER))
+                .into_tuple();
+            assert_eq!(round_id, 1);
+            assert_eq!(price, managed_biguint!(12_000));
+        });
+}
+
+#[test]
+fn test_price_aggregator_round_window() {
+    let mut state = PriceAggregatorTestState::new();
+    state.deploy();
+    state.set_pair_decimals();
+    state.unpause_endpoint();
+
+    state
+        .world
+        .tx()
+        .from(OWNER_ADDRESS)
+        .to(PRICE_AGGREGATOR_ADDRESS)
+        .typed(price_aggregator_proxy::PriceAggregatorProxy)
+        .set_submission_count(SUBMISSION_COUNT + 1)
+        .run();
+
+    // the window cannot lower the quorum below the minimum submission count
+    // nor raise it above the submission count
+    for min_submissions in [SUBMISSION_COUNT - 1, SUBMISSION_COUNT + 2] {
+        state
+            .world
+            .tx()
+            .from(OWNER_ADDRESS)
+            .to(PRICE_AGGREGATOR_ADDRESS)
+            .typed(price_aggregator_proxy::PriceAggregatorProxy)
+            .set_pair_round_window(EGLD_TICKER, USD_TICKER, 60u64, min_submissions)
+            .with_result(ExpectError(4, "invalid min submissions"))
+            .run();
+    }
+
+    state
+        .world
+        .tx()
+        .from(OWNER_ADDRESS)
+        .to(PRICE_AGGREGATOR_ADDRESS)
+        .typed(price_aggregator_proxy::PriceAggregatorProxy)
+        .set_pair_round_window(EGLD_TICKER, USD_TICKER, 60u64, SUBMISSION_COUNT)
+        .run();
+
+    state.submit(&state.oracles[0].clone(), 100, 10_000);
+    state.submit(&state.oracles[1].clone(), 100, 11_000);
+    state.submit(&state.oracles[2].clone(), 100, 12_000);
+
+    state.world.current_block().block_timestamp(120);
+    state
+        .world
+        .tx()
+        .from(&state.oracles[3])
+        .to(PRICE_AGGREGATOR_ADDRESS)
+        .typed(price_aggregator_proxy::PriceAggregatorProxy)
+        .finalize_round(EGLD_TICKER, USD_TICKER)
+        .with_result(ExpectError(4, "round window not elapsed"))
+        .run();
+
+    // anyone can finalize the round once the window has passed
+    state.world.current_block().block_timestamp(160);
+    state
+        .world
+        .tx()
+        .from(&state.oracles[3])
+        .to(PRICE_AGGREGATOR_ADDRESS)
+        .typed(price_aggregator_proxy::PriceAggregatorProxy)
+        .finalize_round(EGLD_TICKER, USD_TICKER)
+        .run();
+    state
+        .world
+        .tx()
+        .from(&state.oracles[3])
+        .to(PRICE_AGGREGATOR_ADDRESS)
+        .typed(price_aggregator_proxy::PriceAggregatorProxy)
+        .finalize_round(EGLD_TICKER, USD_TICKER)
+        .with_result(ExpectError(4, "no pending round"))
+        .run();
+
+    let latest_price = |state: &mut PriceAggregatorTestState| {
+        let mut result = (0u32, 0u64);
+        state
+            .world
+            .whitebox_query(&state.price_aggregator_whitebox, |sc| {
+                let (round_id, _, _, _, price, _) = sc
+                    .latest_price_feed(managed_buffer!(EGLD_TICKER), managed_buffer!(USD_TICKER))
+                    .into_tuple();
+                result = (round_id, price.to_u64().unwrap());
+            });
+        result
+    };
+    assert_eq!(latest_price(&mut state), (1, 11_000));
+
+    // a submission after the window also creates the round
+    state.world.current_block().block_timestamp(200);
+    state.submit(&state.oracles[0].clone(), 200, 12_000);
+    state.submit(&state.oracles[1].clone(), 200, 13_000);
+    state.world.current_block().block_timestamp(260);
+    state.submit(&state.oracles[2].clone(), 260, 14_000);
+    assert_eq!(latest_price(&mut state), (2, 13_000));
+}
+
+#[test]
+fn test_price_aggregator_update_policy() {
+    let mut state = PriceAggregatorTestState::new();
+    state.deploy();
+    state.set_pair_decimals();
+    state.unpause_endpoint();
+
+    // a policy without any condition would freeze the pair
+    state
+        .world
+        .tx()
+        .from(OWNER_ADDRESS)
+        .to(PRICE_AGGREGATOR_ADDRESS)
+        .typed(price_aggregator_proxy::PriceAggregatorProxy)
+        .set_pair_update_policy(EGLD_TICKER, USD_TICKER, 0u64, 0u64, true)
+        .with_result(ExpectError(4, "update policy must enable a condition"))
+        .run();
+
+    // update on 5% moves or every 300 seconds
+    state
+        .world
+        .tx()
+        .from(OWNER_ADDRESS)
+        .to(PRICE_AGGREGATOR_ADDRESS)
+        .typed(price_aggregator_proxy::PriceAggregatorProxy)
+        .set_pair_update_policy(EGLD_TICKER, USD_TICKER, 500u64, 300u64, true)
+        .run();
+
+    for oracle_index in 0..SUBMISSION_COUNT {
+        let oracle = state.oracles[oracle_index].clone();
+        state.submit(&oracle, 100, 10_000);
+    }
+
+    let expect_should_update =
+        |state: &mut PriceAggregatorTestState, price: u64, expected: bool| {
+            state
+                .world
+                .query()
+                .to(PRICE_AGGREGATOR_ADDRESS)
+                .typed(price_aggregator_proxy::PriceAggregatorProxy)
+                .should_update(EGLD_TICKER, USD_TICKER, price)
+                .returns(ExpectValue(expected))
+                .run();
+        };
+
+    state.world.current_block().block_timestamp(110);
+    expect_should_update(&mut state, 10_200, false);
+    expect_should_update(&mut state, 11_000, true);
+    state.submit_and_expect_err(&state.oracles[0].clone(), 110, 10_200, "update not needed");
+    state.submit(&state.oracles[0].clone(), 110, 11_000);
+
+    // the heartbeat makes an update needed regardless of the price
+    state.world.current_block().block_timestamp(400);
+    expect_should_update(&mut state, 10_000, true);
+}
+
+#[test]
+fn test_price_aggregator_baskets() {
+    const MEX_TICKER: &[u8] = b"MEX";
+    const MEX_DECIMALS: u8 = 2;
+    const BASKET_NAME: &[u8] = b"DEFI-INDEX";
+    const UNIT_WEIGHT: u64 = 1_000_000_000_000_000_000;
+
+    let mut state = PriceAggregatorTestState::new();
+    state.deploy();
+    state.set_pair_decimals();
+    state
+        .world
+        .tx()
+        .from(OWNER_ADDRESS)
+        .to(PRICE_AGGREGATOR_ADDRESS)
+        .typed(price_aggregator_proxy::PriceAggregatorProxy)
+        .set_pair_decimals(MEX_TICKER, USD_TICKER, MEX_DECIMALS)
+        .run();
+    state.unpause_endpoint();
+
+    for oracle_index in 0..SUBMISSION_COUNT {
+        let oracle = state.oracles[oracle_index].clone();
+        state.submit(&oracle, 100, 10_000);
+    }
+
+    let constituents = MultiValueVec::from(vec![
+        MultiValue3::from((
+            ManagedBuffer::<StaticApi>::from(EGLD_TICKER),
+            ManagedBuffer::from(USD_TICKER),
+            BigUint::from(UNIT_WEIGHT / 2),
+        )),
+        MultiValue3::from((
+            ManagedBuffer::from(MEX_TICKER),
+            ManagedBuffer::from(USD_TICKER),
+            BigUint::from(2 * UNIT_WEIGHT),
+        )),
+    ]);
+
+    // every constituent must already have rounds
+    state
+        .world
+        .tx()
+        .from(OWNER_ADDRESS)
+        .to(PRICE_AGGREGATOR_ADDRESS)
+        .typed(price_aggregator_proxy::PriceAggregatorProxy)
+        .create_basket(BASKET_NAME, MEX_DECIMALS, constituents.clone())
+        .with_result(ExpectError(4, "constituent pair has no rounds"))
+        .run();
+
+    state.world.current_block().block_timestamp(150);
+    for oracle_index in 0..SUBMISSION_COUNT {
+        state
+            .world
+            .tx()
+            .from(&state.oracles[oracle_index])
+            .to(PRICE_AGGREGATOR_ADDRESS)
+            .typed(price_aggregator_proxy::PriceAggregatorProxy)
+            .submit(
+                MEX_TICKER,
+                USD_TICKER,
+                150u64,
+                250u64,
+                MEX_DECIMALS,
+                OptionalValue::<BigUint<StaticApi>>::None,
+            )
+            .run();
+    }
+
+    state
+        .world
+        .tx()
+        .from(&state.oracles[0])
+        .to(PRICE_AGGREGATOR_ADDRESS)
+        .typed(price_aggregator_proxy::PriceAggregatorProxy)
+        .create_basket(BASKET_NAME, MEX_DECIMALS, constituents.clone())
+        .with_result(ExpectError(4, "Endpoint can only be called by owner"))
+        .run();
+    state
+        .world
+        .tx()
+        .from(OWNER_ADDRESS)
+        .to(PRICE_AGGREGATOR_ADDRESS)
+        .typed(price_aggregator_proxy::PriceAggregatorProxy)
+        .create_basket(BASKET_NAME, MEX_DECIMALS, constituents)
+        .run();
+
+    // 0.5 * 10_000.00 + 2 * 2.50, dated by the oldest constituent
+    state
+        .world
+        .query()
+        .to(PRICE_AGGREGATOR_ADDRESS)
+        .typed(price_aggregator_proxy::PriceAggregatorProxy)
+        .get_basket_price(BASKET_NAME)
+        .returns(ExpectValue(price_aggregator_proxy::BasketPrice {
+            price: BigUint::from(500_500u64),
+            decimals: MEX_DECIMALS,
+            timestamp: 100,
+        }))
+        .run();
+
+    // an active manual price overrides the constituent's latest round, and dates it
+    state
+        .world
+        .tx()
+        .from(OWNER_ADDRESS)
+        .to(PRICE_AGGREGATOR_ADDRESS)
+        .typed(price_aggregator_proxy::PriceAggregatorProxy)
+        .set_manual_price(EGLD_TICKER, USD_TICKER, 12_000u64, 200u64)
+        .run();
+    state
+        .world
+        .query()
+        .to(PRICE_AGGREGATOR_ADDRESS)
+        .typed(price_aggregator_proxy::PriceAggregatorProxy)
+        .get_basket_price(BASKET_NAME)
+        .returns(ExpectValue(price_aggregator_proxy::BasketPrice {
+            price: BigUint::from(600_500u64),
+            decimals: MEX_DECIMALS,
+            timestamp: 150,
+        }))
+        .run();
+
+    state
+        .world
+        .tx()
+        .from(OWNER_ADDRESS)
+        .to(PRICE_AGGREGATOR_ADDRESS)
+        .typed(price_aggregator_proxy::PriceAggregatorProxy)
+        .remove_basket(BASKET_NAME)
+        .run();
+    state
+        .world
+        .query()
+        .to(PRICE_AGGREGATOR_ADDRESS)
+        .typed(price_aggregator_proxy::PriceAggregatorProxy)
+        .get_basket_price(BASKET_NAME)
+        .returns(ExpectError(4, "basket not found"))
+        .run();
+}
+
+#[test]
+fn test_price_aggregator_statistics() {
+    let mut state = PriceAggregatorTestState::new();
+    state.deploy();
+    state.set_pair_decimals();
+    state.unpause_endpoint();
+
+    state
+        .world
+        .tx()
+        .from(OWNER_ADDRESS)
+        .to(PRICE_AGGREGATOR_ADDRESS)
+        .typed(price_aggregator_proxy::PriceAggregatorProxy)
+        .set_ema_half_lives(MultiValueVec::from(vec![60u64]))
+        .run();
+    state
+        .world
+        .tx()
+        .from(OWNER_ADDRESS)
+        .to(PRICE_AGGREGATOR_ADDRESS)
+        .typed(price_aggregator_proxy::PriceAggregatorProxy)
+        .set_volatility_window(2usize)
+        .run();
+
+    let submit_round = |state: &mut PriceAggregatorTestState, timestamp: u64, price: u64| {
+        state.world.current_block().block_timestamp(timestamp);
+        for oracle_index in 0..SUBMISSION_COUNT {
+            let oracle = state.oracles[oracle_index].clone();
+            state.submit(&oracle, timestamp, price);
+        }
+    };
+    let expect_statistics =
+        |state: &mut PriceAggregatorTestState, ema: u64, volatility: u64, sample_count: usize| {
+            state
+                .world
+                .whitebox_query(&state.price_aggregator_whitebox, |sc| {
+                    let ema_state = sc
+                        .get_ema(
+                            managed_buffer!(EGLD_TICKER),
+                            managed_buffer!(USD_TICKER),
+                            60,
+                        )
+                        .into_option()
+                        .unwrap();
+                    assert_eq!(ema_state.value, managed_biguint!(ema));
+
+                    let realized_volatility = sc.get_realized_volatility(
+                        managed_buffer!(EGLD_TICKER),
+                        managed_buffer!(USD_TICKER),
+                    );
+                    assert_eq!(realized_volatility.volatility, managed_biguint!(volatility));
+                    assert_eq!(realized_volatility.sample_count, sample_count);
+                });
+        };
+
+    submit_round(&mut state, 100, 10_000);
+    expect_statistics(&mut state, 10_000, 0, 0);
+
+    // one half-life later, the previous value weighs half; the price moved 20%
+    submit_round(&mut state, 160, 12_000);
+    expect_statistics(&mut state, 11_000, 200_000_000_000_000_000, 1);
+
+    submit_round(&mut state, 220, 12_000);
+    expect_statistics(&mut state, 11_500, 141_421_356_237_309_504, 2);
+
+    // the 25% move replaces the oldest return of the window
+    submit_round(&mut state, 280, 9_000);
+    expect_statistics(&mut state, 10_250, 176_776_695_296_636_881, 2);
+}
+
+#[test]
+fn test_price_aggregator_subscriptions() {
+    let mut state = PriceAggregatorTestState::new();
+    state.deploy();
+    state.set_pair_decimals();
+    state.unpause_endpoint();
+    state
+        .world
+        .account(CONSUMER_ADDRESS)
+        .code(PRICE_CONSUMER_MOCK_PATH)
+        .balance(100);
+
+    state
+        .world
+        .tx()
+        .from(OWNER_ADDRESS)
+        .to(PRICE_AGGREGATOR_ADDRESS)
+        .typed(price_aggregator_proxy::PriceAggregatorProxy)
+        .subscribe(EGLD_TICKER, USD_TICKER, "onPrice", 10_000_000u64)
+        .with_result(ExpectError(4, "only smart contracts can subscribe"))
+        .run();
+    state
+        .world
+        .tx()
+        .from(OWNER_ADDRESS)
+        .to(PRICE_AGGREGATOR_ADDRESS)
+        .typed(price_aggregator_proxy::PriceAggregatorProxy)
+        .set_subscription_fee(EgldOrEsdtTokenIdentifier::egld(), 5u64)
+        .run();
+    state
+        .world
+        .tx()
+        .from(CONSUMER_ADDRESS)
+        .to(PRICE_AGGREGATOR_ADDRESS)
+        .typed(price_aggregator_proxy::PriceAggregatorProxy)
+        .subscribe(EGLD_TICKER, USD_TICKER, "onPrice", 10_000_000u64)
+        .with_result(ExpectError(4, "invalid subscription fee"))
+        .run();
+
+    let subscribe = |state: &mut PriceAggregatorTestState| {
+        state
+            .world
+            .tx()
+            .from(CONSUMER_ADDRESS)
+            .to(PRICE_AGGREGATOR_ADDRESS)
+            .typed(price_aggregator_proxy::PriceAggregatorProxy)
+            .subscribe(EGLD_TICKER, USD_TICKER, "onPrice", 10_000_000u64)
+            .egld(5)
+            .run();
+    };
+
+    // removing a pending subscription refunds the fee
+    subscribe(&mut state);
+    state.world.check_account(CONSUMER_ADDRESS).balance(95);
+    state
+        .world
+        .tx()
+        .from(OWNER_ADDRESS)
+        .to(PRICE_AGGREGATOR_ADDRESS)
+        .typed(price_aggregator_proxy::PriceAggregatorProxy)
+        .remove_subscription(EGLD_TICKER, USD_TICKER, CONSUMER_ADDRESS)
+        .run();
+    state.world.check_account(CONSUMER_ADDRESS).balance(100);
+
+    subscribe(&mut state);
+    state
+        .world
+        .tx()
+        .from(CONSUMER_ADDRESS)
+        .to(PRICE_AGGREGATOR_ADDRESS)
+        .typed(price_aggregator_proxy::PriceAggregatorProxy)
+        .approve_subscription(EGLD_TICKER, USD_TICKER, CONSUMER_ADDRESS)
+        .with_result(ExpectError(4, "Endpoint can only be called by owner"))
+        .run();
+    state
+        .world
+        .tx()
+        .from(OWNER_ADDRESS)
+        .to(PRICE_AGGREGATOR_ADDRESS)
+        .typed(price_aggregator_proxy::PriceAggregatorProxy)
+        .approve_subscription(EGLD_TICKER, USD_TICKER, CONSUMER_ADDRESS)
+        .run();
+    state.world.check_account(OWNER_ADDRESS).balance(5);
+
+    let subscription = state
+        .world
+        .query()
+        .to(PRICE_AGGREGATOR_ADDRESS)
+        .typed(price_aggregator_proxy::PriceAggregatorProxy)
+        .get_subscription(EGLD_TICKER, USD_TICKER, CONSUMER_ADDRESS)
+        .returns(ReturnsResult)
+        .run()
+        .into_option()
+        .unwrap();
+    assert!(subscription.approved);
+    assert_eq!(subscription.consecutive_failures, 0);
+
+    let consumer_whitebox =
+        WhiteboxContract::new(CONSUMER_ADDRESS, price_consumer_mock::contract_obj);
+    let consecutive_failures = |state: &mut PriceAggregatorTestState| {
+        state
+            .world
+            .query()
+            .to(PRICE_AGGREGATOR_ADDRESS)
+            .typed(price_aggregator_proxy::PriceAggregatorProxy)
+            .get_subscription(EGLD_TICKER, USD_TICKER, CONSUMER_ADDRESS)
+            .returns(ReturnsResult)
+            .run()
+            .into_option()
+            .map(|subscription| subscription.consecutive_failures)
+    };
+    let skipped_round = |state: &mut PriceAggregatorTestState, timestamp: u64| {
+        state.world.current_block().block_timestamp(timestamp);
+        for oracle_index in 0..SUBMISSION_COUNT {
+            let oracle = state.oracles[oracle_index].clone();
+            state.submit(&oracle, timestamp, 10_000);
+        }
+    };
+
+    // the default transaction gas cannot cover the notification gas budget,
+    // so the notification is skipped and counted as a failure
+    skipped_round(&mut state, 100);
+    state.world.whitebox_query(&consumer_whitebox, |sc| {
+        assert!(sc.last_price_feed().is_empty());
+    });
+    assert_eq!(consecutive_failures(&mut state), Some(1));
+
+    // the oracle finalizing the round provides the gas of the notification
+    state.world.current_block().block_timestamp(101);
+    for (oracle_index, price) in [(0, 11_000u64), (1, 12_000), (2, 13_000)] {
+        state
+            .world
+            .tx()
+            .from(&state.oracles[oracle_index])
+            .to(PRICE_AGGREGATOR_ADDRESS)
+            .gas(50_000_000u64)
+            .typed(price_aggregator_proxy::PriceAggregatorProxy)
+            .submit(
+                EGLD_TICKER,
+                USD_TICKER,
+                101u64,
+                price,
+                DECIMALS,
+                OptionalValue::<BigUint<StaticApi>>::None,
+            )
+            .run();
+    }
+    state.world.whitebox_query(&consumer_whitebox, |sc| {
+        let price_feed = sc.last_price_feed().get();
+        assert_eq!(price_feed.round_id, 2);
+        assert_eq!(price_feed.from, managed_buffer!(EGLD_TICKER));
+        assert_eq!(price_feed.to, managed_buffer!(USD_TICKER));
+        assert_eq!(price_feed.timestamp, 101);
+        assert_eq!(price_feed.price, managed_biguint!(12_000));
+        assert_eq!(price_feed.decimals, DECIMALS);
+    });
+    assert_eq!(consecutive_failures(&mut state), Some(0));
+
+    // repeatedly skipped notifications remove the subscription
+    for round in 0..MAX_CONSECUTIVE_NOTIFICATION_FAILURES {
+        skipped_round(&mut state, 102 + round as u64);
+    }
+    assert_eq!(consecutive_failures(&mut state), None);
+    state
+        .world
+        .query()
+        .to(PRICE_AGGREGATOR_ADDRESS)
+        .typed(price_aggregator_proxy::PriceAggregatorProxy)
+        .get_subscribers(EGLD_TICKER, USD_TICKER)
+        .returns(ExpectValue(MultiValueVec::<Address>::new()))
+        .run();
+}
+
+#[test]
+fn test_price_aggregator_paid_access() {
+    let mut state = PriceAggregatorTestState::new();
+    state.deploy();
+    state.set_pair_decimals();
+    state.unpause_endpoint();
+    state.world.account(PRICE_CONSUMER_ADDRESS).balance(100);
+    state.world.current_block().block_epoch(1);
+
+    state
+        .world
+        .tx()
+        .from(PRICE_CONSUMER_ADDRESS)
+        .to(PRICE_AGGREGATOR_ADDRESS)
+        .typed(price_aggregator_proxy::PriceAggregatorProxy)
+        .get_price_feed_paid(EGLD_TICKER, USD_TICKER)
+        .egld(10)
+        .with_result(ExpectError(4, "paid access not enabled for this pair"))
+        .run();
+
+    state
+        .world
+        .tx()
+        .from(OWNER_ADDRESS)
+        .to(PRICE_AGGREGATOR_ADDRESS)
+        .typed(price_aggregator_proxy::PriceAggregatorProxy)
+        .set_pair_access_fee(EGLD_TICKER, USD_TICKER, 10u64)
+        .with_result(ExpectError(4, "access fee token not set"))
+        .run();
+    state
+        .world
+        .tx()
+        .from(OWNER_ADDRESS)
+        .to(PRICE_AGGREGATOR_ADDRESS)
+        .typed(price_aggregator_proxy::PriceAggregatorProxy)
+        .set_access_fee_token(EgldOrEsdtTokenIdentifier::egld())
+        .run();
+    state
+        .world
+        .tx()
+        .from(OWNER_ADDRESS)
+        .to(PRICE_AGGREGATOR_ADDRESS)
+        .typed(price_aggregator_proxy::PriceAggregatorProxy)
+        .set_pair_access_fee(EGLD_TICKER, USD_TICKER, 10u64)
+        .run();
+
+    // the first oracle has two accepted submissions in the epoch, the others one each
+    for oracle_index in 0..SUBMISSION_COUNT {
+        let oracle = state.oracles[oracle_index].clone();
+        state.submit(&oracle, 100, 10_000);
+    }
+    let oracle = state.oracles[0].clone();
+    state.submit(&oracle, 100, 10_000);
+
+    state
+        .world
+        .tx()
+        .from(PRICE_CONSUMER_ADDRESS)
+        .to(PRICE_AGGREGATOR_ADDRESS)
+        .typed(price_aggregator_proxy::PriceAggregatorProxy)
+        .get_price_feed_paid(EGLD_TICKER, USD_TICKER)
+        .egld(5)
+        .with_result(ExpectError(4, "invalid access fee"))
+        .run();
+    for _ in 0..2 {
+        let price_feed = state
+            .world
+            .tx()
+            .from(PRICE_CONSUMER_ADDRESS)
+            .to(PRICE_AGGREGATOR_ADDRESS)
+            .typed(price_aggregator_proxy::PriceAggregatorProxy)
+            .get_price_feed_paid(EGLD_TICKER, USD_TICKER)
+            .egld(10)
+            .returns(ReturnsResult)
+            .run();
+        assert_eq!(price_feed.round_id, 1);
+        assert_eq!(price_feed.price, BigUint::from(10_000u64));
+    }
+    state
+        .world
+        .check_account(PRICE_CONSUMER_ADDRESS)
+        .balance(80);
+
+    state
+        .world
+        .tx()
+        .from(OWNER_ADDRESS)
+        .to(PRICE_AGGREGATOR_ADDRESS)
+        .typed(price_aggregator_proxy::PriceAggregatorProxy)
+        .set_access_fee_token(EgldOrEsdtTokenIdentifier::esdt(STAKE_TOKEN_ID))
+        .with_result(ExpectError(4, "fees already collected this epoch"))
+        .run();
+    state
+        .world
+        .tx()
+        .from(&oracle)
+        .to(PRICE_AGGREGATOR_ADDRESS)
+        .typed(price_aggregator_proxy::PriceAggregatorProxy)
+        .claim_revenue(MultiValueVec::from(vec![1u64]))
+        .with_result(ExpectError(4, "epoch not ended"))
+        .run();
+
+    state.world.current_block().block_epoch(2);
+    state
+        .world
+        .tx()
+        .from(OWNER_ADDRESS)
+        .to(PRICE_AGGREGATOR_ADDRESS)
+        .typed(price_aggregator_proxy::PriceAggregatorProxy)
+        .withdraw_unclaimable_revenue(1u64)
+        .with_result(ExpectError(4, "revenue can be claimed by oracles"))
+        .run();
+
+    let expected_shares = [10u64, 5, 5, 0];
+    for (oracle_index, expected_share) in expected_shares.iter().enumerate() {
+        let oracle = state.oracles[oracle_index].clone();
+        state
+            .world
+            .query()
+            .to(PRICE_AGGREGATOR_ADDRESS)
+            .typed(price_aggregator_proxy::PriceAggregatorProxy)
+            .get_claimable_revenue(oracle.to_address(), 1u64)
+            .returns(ExpectValue(*expected_share))
+            .run();
+        state
+            .world
+            .tx()
+            .from(&oracle)
+            .to(PRICE_AGGREGATOR_ADDRESS)
+            .typed(price_aggregator_proxy::PriceAggregatorProxy)
+            .claim_revenue(MultiValueVec::from(vec![1u64]))
+            .run();
+        state.world.check_account(&oracle).balance(*expected_share);
+    }
+
+    // a claimed share cannot be claimed again
+    state
+        .world
+        .tx()
+        .from(&oracle)
+        .to(PRICE_AGGREGATOR_ADDRESS)
+        .typed(price_aggregator_proxy::PriceAggregatorProxy)
+        .claim_revenue(MultiValueVec::from(vec![1u64]))
+        .run();
+    state.world.check_account(&oracle).balance(10);
+
+    // a key rotation carries the unclaimed revenue over to the new address
+    let rotated_oracle = state.oracles[1].clone();
+    let new_oracle = TestAddress::new("oracle-new-key");
+    state.world.account(new_oracle).nonce(1);
+    state.submit(&rotated_oracle, 100, 10_000);
+    state.submit(&state.oracles[2].clone(), 100, 10_000);
+    state
+        .world
+        .tx()
+        .from(PRICE_CONSUMER_ADDRESS)
+        .to(PRICE_AGGREGATOR_ADDRESS)
+        .typed(price_aggregator_proxy::PriceAggregatorProxy)
+        .get_price_feed_paid(EGLD_TICKER, USD_TICKER)
+        .egld(10)
+        .run();
+    state
+        .world
+        .tx()
+        .from(&rotated_oracle)
+        .to(PRICE_AGGREGATOR_ADDRESS)
+        .typed(price_aggregator_proxy::PriceAggregatorProxy)
+        .propose_key_rotation(new_oracle)
+        .run();
+    state
+        .world
+        .tx()
+        .from(new_oracle)
+        .to(PRICE_AGGREGATOR_ADDRESS)
+        .typed(price_aggregator_proxy::PriceAggregatorProxy)
+        .accept_key_rotation(rotated_oracle.to_address())
+        .run();
+
+    state.world.current_block().block_epoch(3);
+    state
+        .world
+        .tx()
+        .from(&rotated_oracle)
+        .to(PRICE_AGGREGATOR_ADDRESS)
+        .typed(price_aggregator_proxy::PriceAggregatorProxy)
+        .claim_revenue(MultiValueVec::from(vec![2u64]))
+        .run();
+    state.world.check_account(&rotated_oracle).balance(5);
+    state
+        .world
+        .tx()
+        .from(new_oracle)
+        .to(PRICE_AGGREGATOR_ADDRESS)
+        .typed(price_aggregator_proxy::PriceAggregatorProxy)
+        .claim_revenue(MultiValueVec::from(vec![2u64]))
+        .run();
+    state.world.check_account(new_oracle).balance(5);
+}
+
+#[test]
+fn test_price_aggregator_confidence() {
+    let mut state = PriceAggregatorTestState::new();
+    state.deploy();
+    state.set_pair_decimals();
+    state.unpause_endpoint();
+
+    let submit_with_confidence =
+        |state: &mut PriceAggregatorTestState, oracle_index: usize, price: u64, confidence: u64| {
+            state
+                .world
+                .tx()
+                .from(&state.oracles[oracle_index])
+                .to(PRICE_AGGREGATOR_ADDRESS)
+                .typed(price_aggregator_proxy::PriceAggregatorProxy)
+                .submit(
+                    EGLD_TICKER,
+                    USD_TICKER,
+                    100u64,
+                    price,
+                    DECIMALS,
+                    OptionalValue::Some(BigUint::from(confidence)),
+                )
+                .run();
+        };
+    let expect_round =
+        |state: &mut PriceAggregatorTestState, price: u64, confidence: Option<(u64, usize)>| {
+            let price_feed = state
+                .world
+                .query()
+                .to(PRICE_AGGREGATOR_ADDRESS)
+                .typed(price_aggregator_proxy::PriceAggregatorProxy)
+                .latest_price_feed(EGLD_TICKER, USD_TICKER)
+                .returns(ReturnsResult)
+                .run();
+            assert_eq!(price_feed.into_tuple().4, BigUint::from(price));
+
+            let round_confidence = state
+                .world
+                .query()
+                .to(PRICE_AGGREGATOR_ADDRESS)
+                .typed(price_aggregator_proxy::PriceAggregatorProxy)
+                .get_round_confidence(EGLD_TICKER, USD_TICKER)
+                .returns(ReturnsResult)
+                .run()
+                .into_option()
+                .map(|round_confidence| {
+                    (
+                        round_confidence.confidence.to_u64().unwrap(),
+                        round_confidence.sample_count,
+                    )
+                });
+            assert_eq!(round_confidence, confidence);
+        };
+
+    // without a threshold, every submission counts
+    submit_with_confidence(&mut state, 0, 10_000, 50);
+    submit_with_confidence(&mut state, 1, 20_000, 5_000);
+    submit_with_confidence(&mut state, 2, 10_200, 100);
+    expect_round(&mut state, 10_200, Some((100, 3)));
+
+    state
+        .world
+        .tx()
+        .from(OWNER_ADDRESS)
+        .to(PRICE_AGGREGATOR_ADDRESS)
+        .typed(price_aggregator_proxy::PriceAggregatorProxy)
+        .set_pair_max_confidence(EGLD_TICKER, USD_TICKER, 20_000u64)
+        .with_result(ExpectError(4, "invalid max confidence"))
+        .run();
+    state
+        .world
+        .tx()
+        .from(OWNER_ADDRESS)
+        .to(PRICE_AGGREGATOR_ADDRESS)
+        .typed(price_aggregator_proxy::PriceAggregatorProxy)
+        .set_pair_max_confidence(EGLD_TICKER, USD_TICKER, 1_000u64)
+        .run();
+
+    // the 25% wide interval is left out
+    submit_with_confidence(&mut state, 0, 10_000, 50);
+    submit_with_confidence(&mut state, 1, 20_000, 5_000);
+    let pending_round = state
+        .world
+        .query()
+        .to(PRICE_AGGREGATOR_ADDRESS)
+        .typed(price_aggregator_proxy::PriceAggregatorProxy)
+        .get_pending_round(EGLD_TICKER, USD_TICKER)
+        .returns(ReturnsResult)
+        .run();
+    assert_eq!(pending_round.submission_count, 2);
+    assert_eq!(pending_round.median, Some(BigUint::from(10_000u64)));
+    state
+        .world
+        .tx()
+        .from(&state.oracles[2])
+        .to(PRICE_AGGREGATOR_ADDRESS)
+        .typed(price_aggregator_proxy::PriceAggregatorProxy)
+        .submit_batch_with_confidence(MultiValueVec::from(vec![MultiValue6::from((
+            EGLD_TICKER,
+            USD_TICKER,
+            100u64,
+            10_200u64,
+            DECIMALS,
+            100u64,
+        ))]))
+        .run();
+    expect_round(&mut state, 10_100, Some((75, 2)));
+
+    // every submission is used when all of them are too wide
+    submit_with_confidence(&mut state, 0, 10_000, 2_000);
+    submit_with_confidence(&mut state, 1, 20_000, 5_000);
+    submit_with_confidence(&mut state, 2, 10_200, 3_000);
+    expect_round(&mut state, 10_200, Some((3_000, 3)));
+
+    for oracle_index in 0..SUBMISSION_COUNT {
+        let oracle = state.oracles[oracle_index].clone();
+        state.submit(&oracle, 100, 10_000);
+    }
+    expect_round(&mut state, 10_000, None);
+}
+
+#[test]
+fn test_price_aggregator_pair_metadata() {
+    const USDC_TOKEN_ID: TestTokenIdentifier = TestTokenIdentifier::new("USDC-c76f1f");
+    const USDT_TOKEN_ID: TestTokenIdentifier = TestTokenIdentifier::new("USDT-f8c08c");
+
+    let mut state = PriceAggregatorTestState::new();
+    state.deploy();
+
+    for (from, to) in [
+        (&b"egld"[..], USD_TICKER),
+        (EGLD_TICKER, &b"USDC-c76f1f"[..]),
+        (EGLD_TICKER, EGLD_TICKER),
+    ] {
+        state
+            .world
+            .tx()
+            .from(OWNER_ADDRESS)
+            .to(PRICE_AGGREGATOR_ADDRESS)
+            .typed(price_aggregator_proxy::PriceAggregatorProxy)
+            .set_pair_decimals(from, to, DECIMALS)
+            .with_result(ExpectError(
+                4,
+                "pair tickers must be distinct uppercase alphanumeric tickers",
+            ))
+            .run();
+    }
+
+    // submissions for a pair configured before the check are rejected
+    state.world.whitebox_call(
+        &state.price_aggregator_whitebox,
+        ScCallStep::new().from(OWNER_ADDRESS),
+        |sc| {
+            sc.pair_decimals(&managed_buffer!(b"egld"), &managed_buffer!(USD_TICKER))
+                .set(Some(DECIMALS));
+        },
+    );
+    state.unpause_endpoint();
+    state
+        .world
+        .tx()
+        .from(&state.oracles[0])
+        .to(PRICE_AGGREGATOR_ADDRESS)
+        .typed(price_aggregator_proxy::PriceAggregatorProxy)
+        .submit(
+            "egld",
+            USD_TICKER,
+            100u64,
+            10_000u64,
+            DECIMALS,
+            OptionalValue::<BigUint<StaticApi>>::None,
+        )
+        .with_result(ExpectError(
+            4,
+            "pair tickers must be distinct uppercase alphanumeric tickers",
+        ))
+        .run();
+
+    state
+        .world
+        .tx()
+        .from(OWNER_ADDRESS)
+        .to(PRICE_AGGREGATOR_ADDRESS)
+        .typed(price_aggregator_proxy::PriceAggregatorProxy)
+        .set_pair_metadata(
+            EGLD_TICKER,
+            USD_TICKER,
+            Option::<TokenIdentifier<StaticApi>>::None,
+            Some(TokenIdentifier::from("usdc")),
+            "EGLD in USDC",
+            "crypto",
+        )
+        .with_result(ExpectError(4, "invalid token identifier"))
+        .run();
+    state
+        .world
+        .tx()
+        .from(OWNER_ADDRESS)
+        .to(PRICE_AGGREGATOR_ADDRESS)
+        .typed(price_aggregator_proxy::PriceAggregatorProxy)
+        .set_pair_metadata(
+            EGLD_TICKER,
+            USD_TICKER,
+            Option::<TokenIdentifier<StaticApi>>::None,
+            Some(TokenIdentifier::from(USDC_TOKEN_ID)),
+            "EGLD in USDC",
+            "crypto",
+        )
+        .run();
+
+    let metadata = state
+        .world
+        .query()
+        .to(PRICE_AGGREGATOR_ADDRESS)
+        .typed(price_aggregator_proxy::PriceAggregatorProxy)
+        .get_pair_metadata(EGLD_TICKER, USD_TICKER)
+        .returns(ReturnsResult)
+        .run()
+        .into_option()
+        .unwrap();
+    assert_eq!(metadata.from_token, None);
+    assert_eq!(
+        metadata.to_token,
+        Some(TokenIdentifier::from(USDC_TOKEN_ID))
+    );
+    assert_eq!(metadata.description, ManagedBuffer::from("EGLD in USDC"));
+    assert_eq!(metadata.category, ManagedBuffer::from("crypto"));
+
+    let expect_pairs_for_token =
+        |state: &mut PriceAggregatorTestState, token: TestTokenIdentifier, pair_count: usize| {
+            let pairs = state
+                .world
+                .query()
+                .to(PRICE_AGGREGATOR_ADDRESS)
+                .typed(price_aggregator_proxy::PriceAggregatorProxy)
+                .get_pairs_for_token(token)
+                .returns(ReturnsResult)
+                .run()
+                .into_iter()
+                .map(|pair| pair.into_tuple())
+                .collect::<Vec<_>>();
+            assert_eq!(pairs.len(), pair_count);
+            for (from, to) in pairs {
+                assert_eq!(from, ManagedBuffer::from(EGLD_TICKER));
+                assert_eq!(to, ManagedBuffer::from(USD_TICKER));
+            }
+        };
+    expect_pairs_for_token(&mut state, USDC_TOKEN_ID, 1);
+
+    // changing the token of a leg moves the pair to the new token
+    state
+        .world
+        .tx()
+        .from(OWNER_ADDRESS)
+        .to(PRICE_AGGREGATOR_ADDRESS)
+        .typed(price_aggregator_proxy::PriceAggregatorProxy)
+        .set_pair_metadata(
+            EGLD_TICKER,
+            USD_TICKER,
+            Option::<TokenIdentifier<StaticApi>>::None,
+            Some(TokenIdentifier::from(USDT_TOKEN_ID)),
+            "EGLD in USDT",
+            "crypto",
+        )
+        .run();
+    expect_pairs_for_token(&mut state, USDC_TOKEN_ID, 0);
+    expect_pairs_for_token(&mut state, USDT_TOKEN_ID, 1);
+
+    state
+        .world
+        .tx()
+        .from(OWNER_ADDRESS)
+        .to(PRICE_AGGREGATOR_ADDRESS)
+        .typed(price_aggregator_proxy::PriceAggregatorProxy)
+        .clear_pair_metadata(EGLD_TICKER, USD_TICKER)
+        .run();
+    expect_pairs_for_token(&mut state, USDT_TOKEN_ID, 0);
+    state
+        .world
+        .query()
+        .to(PRICE_AGGREGATOR_ADDRESS)
+        .typed(price_aggregator_proxy::PriceAggregatorProxy)
+        .get_pair_metadata(EGLD_TICKER, USD_TICKER)
+        .returns(ExpectValue(
+            OptionalValue::<price_aggregator_proxy::PairMetadata<StaticApi>>::None,
+        ))
+        .run();
+}
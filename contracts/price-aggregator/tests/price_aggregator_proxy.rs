@@ -72,6 +72,26 @@ where
     }
 }
 
+#[rustfmt::skip]
+impl<Env, From, To, Gas> PriceAggregatorProxyMethods<Env, From, To, Gas>
+where
+    Env: TxEnv,
+    Env::Api: VMApi,
+    From: TxFrom<Env>,
+    To: TxTo<Env>,
+    Gas: TxGas<Env>,
+{
+    /// Keeps the state of the previous version, migrating it to the current storage layout. 
+    pub fn upgrade(
+        self,
+    ) -> TxTypedUpgrade<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_upgrade()
+            .original_result()
+    }
+}
+
 #[rustfmt::skip]
 impl<Env, From, To, Gas> PriceAggregatorProxyMethods<Env, From, To, Gas>
 where
@@ -97,6 +117,35 @@ where
             .original_result()
     }
 
+    pub fn set_staking_token_weight<
+        Arg0: ProxyArg<EgldOrEsdtTokenIdentifier<Env::Api>>,
+        Arg1: ProxyArg<u64>,
+    >(
+        self,
+        token: Arg0,
+        weight: Arg1,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("setStakingTokenWeight")
+            .argument(&token)
+            .argument(&weight)
+            .original_result()
+    }
+
+    pub fn remove_staking_token<
+        Arg0: ProxyArg<EgldOrEsdtTokenIdentifier<Env::Api>>,
+    >(
+        self,
+        token: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("removeStakingToken")
+            .argument(&token)
+            .original_result()
+    }
+
     pub fn add_oracles<
         Arg0: ProxyArg<MultiValueEncoded<Env::Api, ManagedAddress<Env::Api>>>,
     >(
@@ -128,12 +177,71 @@ where
             .original_result()
     }
 
+    /// Restricts the pair to the given oracles. 
+    /// Rounds for the pair are then created after `submission_count` submissions from this set. 
+    pub fn set_pair_oracles<
+        Arg0: ProxyArg<ManagedBuffer<Env::Api>>,
+        Arg1: ProxyArg<ManagedBuffer<Env::Api>>,
+        Arg2: ProxyArg<usize>,
+        Arg3: ProxyArg<MultiValueEncoded<Env::Api, ManagedAddress<Env::Api>>>,
+    >(
+        self,
+        from: Arg0,
+        to: Arg1,
+        submission_count: Arg2,
+        oracles: Arg3,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("setPairOracles")
+            .argument(&from)
+            .argument(&to)
+            .argument(&submission_count)
+            .argument(&oracles)
+            .original_result()
+    }
+
+    /// Allows every oracle to submit for the pair again. 
+    pub fn clear_pair_oracles<
+        Arg0: ProxyArg<ManagedBuffer<Env::Api>>,
+        Arg1: ProxyArg<ManagedBuffer<Env::Api>>,
+    >(
+        self,
+        from: Arg0,
+        to: Arg1,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("clearPairOracles")
+            .argument(&from)
+            .argument(&to)
+            .original_result()
+    }
+
+    pub fn get_pair_oracles<
+        Arg0: ProxyArg<ManagedBuffer<Env::Api>>,
+        Arg1: ProxyArg<ManagedBuffer<Env::Api>>,
+    >(
+        self,
+        from: Arg0,
+        to: Arg1,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, MultiValueEncoded<Env::Api, ManagedAddress<Env::Api>>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getPairOracles")
+            .argument(&from)
+            .argument(&to)
+            .original_result()
+    }
+
+    /// The optional confidence is the half-width of the interval around the price, with the same decimals. 
     pub fn submit<
         Arg0: ProxyArg<ManagedBuffer<Env::Api>>,
         Arg1: ProxyArg<ManagedBuffer<Env::Api>>,
         Arg2: ProxyArg<u64>,
         Arg3: ProxyArg<BigUint<Env::Api>>,
         Arg4: ProxyArg<u8>,
+        Arg5: ProxyArg<OptionalValue<BigUint<Env::Api>>>,
     >(
         self,
         from: Arg0,
@@ -141,6 +249,7 @@ where
         submission_timestamp: Arg2,
         price: Arg3,
         decimals: Arg4,
+        opt_confidence: Arg5,
     ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
         self.wrapped_tx
             .payment(NotPayable)
@@ -150,6 +259,21 @@ where
             .argument(&submission_timestamp)
             .argument(&price)
             .argument(&decimals)
+            .argument(&opt_confidence)
+            .original_result()
+    }
+
+    /// When enabled, a second submission from an oracle replaces its price in the round in progress. 
+    pub fn set_submission_updates_enabled<
+        Arg0: ProxyArg<bool>,
+    >(
+        self,
+        enabled: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("setSubmissionUpdatesEnabled")
+            .argument(&enabled)
             .original_result()
     }
 
@@ -166,6 +290,95 @@ where
             .original_result()
     }
 
+    /// Same as `submitBatch`, with the confidence of each price as in `submit`. 
+    pub fn submit_batch_with_confidence<
+        Arg0: ProxyArg<MultiValueEncoded<Env::Api, MultiValue6<ManagedBuffer<Env::Api>, ManagedBuffer<Env::Api>, u64, BigUint<Env::Api>, u8, BigUint<Env::Api>>>>,
+    >(
+        self,
+        submissions: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("submitBatchWithConfidence")
+            .argument(&submissions)
+            .original_result()
+    }
+
+    /// Rounds are created at the end of the window if at least `min_submissions` were made, 
+    /// instead of waiting for the submission count. The window must end before the round is discarded. 
+    /// `min_submissions` cannot go below the minimum submission count nor above the pair's submission count. 
+    pub fn set_pair_round_window<
+        Arg0: ProxyArg<ManagedBuffer<Env::Api>>,
+        Arg1: ProxyArg<ManagedBuffer<Env::Api>>,
+        Arg2: ProxyArg<u64>,
+        Arg3: ProxyArg<usize>,
+    >(
+        self,
+        from: Arg0,
+        to: Arg1,
+        window_seconds: Arg2,
+        min_submissions: Arg3,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("setPairRoundWindow")
+            .argument(&from)
+            .argument(&to)
+            .argument(&window_seconds)
+            .argument(&min_submissions)
+            .original_result()
+    }
+
+    pub fn clear_pair_round_window<
+        Arg0: ProxyArg<ManagedBuffer<Env::Api>>,
+        Arg1: ProxyArg<ManagedBuffer<Env::Api>>,
+    >(
+        self,
+        from: Arg0,
+        to: Arg1,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("clearPairRoundWindow")
+            .argument(&from)
+            .argument(&to)
+            .original_result()
+    }
+
+    /// Lets anyone create the round once its window has passed, 
+    /// when no further submission arrives to trigger it. 
+    pub fn finalize_round<
+        Arg0: ProxyArg<ManagedBuffer<Env::Api>>,
+        Arg1: ProxyArg<ManagedBuffer<Env::Api>>,
+    >(
+        self,
+        from: Arg0,
+        to: Arg1,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("finalizeRound")
+            .argument(&from)
+            .argument(&to)
+            .original_result()
+    }
+
+    pub fn get_pair_round_window<
+        Arg0: ProxyArg<ManagedBuffer<Env::Api>>,
+        Arg1: ProxyArg<ManagedBuffer<Env::Api>>,
+    >(
+        self,
+        from: Arg0,
+        to: Arg1,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, OptionalValue<RoundWindow>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getPairRoundWindow")
+            .argument(&from)
+            .argument(&to)
+            .original_result()
+    }
+
     pub fn latest_round_data(
         self,
     ) -> TxTypedCall<Env, From, To, NotPayable, Gas, MultiValueEncoded<Env::Api, PriceFeed<Env::Api>>> {
@@ -175,6 +388,37 @@ where
             .original_result()
     }
 
+    /// Pages through the latest price feeds of every pair. An empty page is returned past the last pair. 
+    pub fn latest_round_data_paginated<
+        Arg0: ProxyArg<usize>,
+        Arg1: ProxyArg<usize>,
+    >(
+        self,
+        start_index: Arg0,
+        page_size: Arg1,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, MultiValueEncoded<Env::Api, PriceFeed<Env::Api>>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("latestRoundDataPaginated")
+            .argument(&start_index)
+            .argument(&page_size)
+            .original_result()
+    }
+
+    /// Returns the latest price feed of each requested pair, in order, or `None` for pairs without one. 
+    pub fn latest_price_feeds<
+        Arg0: ProxyArg<MultiValueEncoded<Env::Api, MultiValue2<ManagedBuffer<Env::Api>, ManagedBuffer<Env::Api>>>>,
+    >(
+        self,
+        pairs: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, MultiValueEncoded<Env::Api, Option<PriceFeed<Env::Api>>>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("latestPriceFeeds")
+            .argument(&pairs)
+            .original_result()
+    }
+
     pub fn latest_price_feed<
         Arg0: ProxyArg<ManagedBuffer<Env::Api>>,
         Arg1: ProxyArg<ManagedBuffer<Env::Api>>,
@@ -191,6 +435,58 @@ where
             .original_result()
     }
 
+    /// Same as `latestPriceFeed`, followed by the source of the price: `Oracles` or `Manual`. 
+    pub fn latest_price_feed_with_source<
+        Arg0: ProxyArg<ManagedBuffer<Env::Api>>,
+        Arg1: ProxyArg<ManagedBuffer<Env::Api>>,
+    >(
+        self,
+        from: Arg0,
+        to: Arg1,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, MultiValue7<u32, ManagedBuffer<Env::Api>, ManagedBuffer<Env::Api>, u64, BigUint<Env::Api>, u8, PriceSource>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("latestPriceFeedWithSource")
+            .argument(&from)
+            .argument(&to)
+            .original_result()
+    }
+
+    /// Same as `latestPriceFeed`, for consumers paying the pair's access fee. 
+    /// The fees are shared among the oracles, see `claimRevenue`. 
+    pub fn get_price_feed_paid<
+        Arg0: ProxyArg<ManagedBuffer<Env::Api>>,
+        Arg1: ProxyArg<ManagedBuffer<Env::Api>>,
+    >(
+        self,
+        from: Arg0,
+        to: Arg1,
+    ) -> TxTypedCall<Env, From, To, (), Gas, PriceFeed<Env::Api>> {
+        self.wrapped_tx
+            .raw_call("getPriceFeedPaid")
+            .argument(&from)
+            .argument(&to)
+            .original_result()
+    }
+
+    /// Reads the pair's fallback aggregator when the contract is paused, 
+    /// or when the local price feed is missing or older than the configured maximum age. 
+    pub fn latest_price_feed_with_fallback<
+        Arg0: ProxyArg<ManagedBuffer<Env::Api>>,
+        Arg1: ProxyArg<ManagedBuffer<Env::Api>>,
+    >(
+        self,
+        from: Arg0,
+        to: Arg1,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, MultiValue7<u32, ManagedBuffer<Env::Api>, ManagedBuffer<Env::Api>, u64, BigUint<Env::Api>, u8, PriceSource>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("latestPriceFeedWithFallback")
+            .argument(&from)
+            .argument(&to)
+            .original_result()
+    }
+
     pub fn latest_price_feed_optional<
         Arg0: ProxyArg<ManagedBuffer<Env::Api>>,
         Arg1: ProxyArg<ManagedBuffer<Env::Api>>,
@@ -220,6 +516,34 @@ where
             .original_result()
     }
 
+    /// Anyone can execute a proposal once enough board members voted for it. 
+    pub fn execute_admin_action<
+        Arg0: ProxyArg<u64>,
+    >(
+        self,
+        proposal_id: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("executeAdminAction")
+            .argument(&proposal_id)
+            .original_result()
+    }
+
+    /// Anyone can execute a timelocked action once its execution timestamp has passed. 
+    pub fn execute_timelocked_action<
+        Arg0: ProxyArg<u64>,
+    >(
+        self,
+        action_id: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("executeTimelockedAction")
+            .argument(&action_id)
+            .original_result()
+    }
+
     pub fn get_oracles(
         self,
     ) -> TxTypedCall<Env, From, To, NotPayable, Gas, MultiValueEncoded<Env::Api, ManagedAddress<Env::Api>>> {
@@ -264,15 +588,6 @@ where
             .original_result()
     }
 
-    pub fn submission_count(
-        self,
-    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, usize> {
-        self.wrapped_tx
-            .payment(NotPayable)
-            .raw_call("submission_count")
-            .original_result()
-    }
-
     pub fn pause_endpoint(
         self,
     ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
@@ -308,16 +623,20 @@ where
             .original_result()
     }
 
+    /// Unstakes from the given token, or from the token set at deploy if none is given. 
     pub fn unstake<
         Arg0: ProxyArg<BigUint<Env::Api>>,
+        Arg1: ProxyArg<OptionalValue<EgldOrEsdtTokenIdentifier<Env::Api>>>,
     >(
         self,
         unstake_amount: Arg0,
+        opt_token: Arg1,
     ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
         self.wrapped_tx
             .payment(NotPayable)
             .raw_call("unstake")
             .argument(&unstake_amount)
+            .argument(&opt_token)
             .original_result()
     }
 
@@ -359,20 +678,1657 @@ where
             .argument(&member_to_slash)
             .original_result()
     }
-}
 
-#[type_abi]
-#[derive(NestedEncode, NestedDecode, TopEncode, TopDecode)]
-pub struct PriceFeed<Api>
-where
-    Api: ManagedTypeApi,
-{
-    pub round_id: u32,
-    pub from: ManagedBuffer<Api>,
-    pub to: ManagedBuffer<Api>,
-    pub timestamp: u64,
-    pub price: BigUint<Api>,
-    pub decimals: u8,
+    /// Weighted sum of the member's stake over all the accepted staking tokens. 
+    pub fn get_staked_amount<
+        Arg0: ProxyArg<ManagedAddress<Env::Api>>,
+    >(
+        self,
+        user: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, BigUint<Env::Api>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getStakedAmount")
+            .argument(&user)
+            .original_result()
+    }
+
+    pub fn get_board_members(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, MultiValueEncoded<Env::Api, ManagedAddress<Env::Api>>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getBoardMembers")
+            .original_result()
+    }
+
+    pub fn get_staking_tokens(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, MultiValueEncoded<Env::Api, MultiValue2<EgldOrEsdtTokenIdentifier<Env::Api>, u64>>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getStakingTokens")
+            .original_result()
+    }
+
+    pub fn staked_token_amount<
+        Arg0: ProxyArg<ManagedAddress<Env::Api>>,
+        Arg1: ProxyArg<EgldOrEsdtTokenIdentifier<Env::Api>>,
+    >(
+        self,
+        user: Arg0,
+        token: Arg1,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, BigUint<Env::Api>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getStakedTokenAmount")
+            .argument(&user)
+            .argument(&token)
+            .original_result()
+    }
+
+    pub fn submission_count(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, usize> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("submission_count")
+            .original_result()
+    }
+
+    pub fn submission_updates_enabled(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, bool> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getSubmissionUpdatesEnabled")
+            .original_result()
+    }
+
+    /// First step of a key rotation, called by the oracle with its current address. 
+    /// A new proposal overrides the previous one. 
+    pub fn propose_key_rotation<
+        Arg0: ProxyArg<ManagedAddress<Env::Api>>,
+    >(
+        self,
+        new_address: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("proposeKeyRotation")
+            .argument(&new_address)
+            .original_result()
+    }
+
+    pub fn cancel_key_rotation(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("cancelKeyRotation")
+            .original_result()
+    }
+
+    /// Second step of a key rotation, called from the new address. 
+    /// Moves the oracle status, stake, board membership, slash, proposal and application votes, 
+    /// per-pair assignments, pending submissions and unclaimed revenue to the new address. 
+    pub fn accept_key_rotation<
+        Arg0: ProxyArg<ManagedAddress<Env::Api>>,
+    >(
+        self,
+        old_address: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("acceptKeyRotation")
+            .argument(&old_address)
+            .original_result()
+    }
+
+    pub fn pending_key_rotation<
+        Arg0: ProxyArg<ManagedAddress<Env::Api>>,
+    >(
+        self,
+        oracle: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ManagedAddress<Env::Api>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getPendingKeyRotation")
+            .argument(&oracle)
+            .original_result()
+    }
+
+    /// Candidates pay the required stake in one of the staking tokens. 
+    /// The stake is refunded if the application is rejected. 
+    pub fn apply_as_oracle<
+        Arg0: ProxyArg<ManagedBuffer<Env::Api>>,
+        Arg1: ProxyArg<ManagedBuffer<Env::Api>>,
+    >(
+        self,
+        operator_name: Arg0,
+        contact: Arg1,
+    ) -> TxTypedCall<Env, From, To, (), Gas, ()> {
+        self.wrapped_tx
+            .raw_call("applyAsOracle")
+            .argument(&operator_name)
+            .argument(&contact)
+            .original_result()
+    }
+
+    pub fn approve_oracle_application<
+        Arg0: ProxyArg<ManagedAddress<Env::Api>>,
+    >(
+        self,
+        candidate: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("approveOracleApplication")
+            .argument(&candidate)
+            .original_result()
+    }
+
+    pub fn reject_oracle_application<
+        Arg0: ProxyArg<ManagedAddress<Env::Api>>,
+    >(
+        self,
+        candidate: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("rejectOracleApplication")
+            .argument(&candidate)
+            .original_result()
+    }
+
+    /// Board members vote for or against a candidate. 
+    /// The application is resolved as soon as either side reaches the slash quorum, 
+    /// counting only votes from members that are still staked board members. 
+    pub fn vote_oracle_application<
+        Arg0: ProxyArg<ManagedAddress<Env::Api>>,
+        Arg1: ProxyArg<bool>,
+    >(
+        self,
+        candidate: Arg0,
+        approve: Arg1,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("voteOracleApplication")
+            .argument(&candidate)
+            .argument(&approve)
+            .original_result()
+    }
+
+    pub fn get_oracle_applications(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, MultiValueEncoded<Env::Api, MultiValue2<ManagedAddress<Env::Api>, OracleApplication<Env::Api>>>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getOracleApplications")
+            .original_result()
+    }
+
+    /// Only votes from members that are still staked board members are counted. 
+    pub fn get_application_vote_count<
+        Arg0: ProxyArg<ManagedAddress<Env::Api>>,
+        Arg1: ProxyArg<bool>,
+    >(
+        self,
+        candidate: Arg0,
+        approve: Arg1,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, usize> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getApplicationVoteCount")
+            .argument(&candidate)
+            .argument(&approve)
+            .original_result()
+    }
+
+    pub fn set_governance_config<
+        Arg0: ProxyArg<usize>,
+        Arg1: ProxyArg<u64>,
+    >(
+        self,
+        quorum: Arg0,
+        voting_period_seconds: Arg1,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("setGovernanceConfig")
+            .argument(&quorum)
+            .argument(&voting_period_seconds)
+            .original_result()
+    }
+
+    /// Staked board members propose an administrative action, which counts as their vote as well. 
+    pub fn propose_admin_action<
+        Arg0: ProxyArg<AdminAction<Env::Api>>,
+    >(
+        self,
+        action: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, u64> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("proposeAdminAction")
+            .argument(&action)
+            .original_result()
+    }
+
+    pub fn vote_admin_action<
+        Arg0: ProxyArg<u64>,
+    >(
+        self,
+        proposal_id: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("voteAdminAction")
+            .argument(&proposal_id)
+            .original_result()
+    }
+
+    pub fn cancel_vote_admin_action<
+        Arg0: ProxyArg<u64>,
+    >(
+        self,
+        proposal_id: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("cancelVoteAdminAction")
+            .argument(&proposal_id)
+            .original_result()
+    }
+
+    /// Only votes from members that are still staked board members are counted. 
+    pub fn get_proposal_vote_count<
+        Arg0: ProxyArg<u64>,
+    >(
+        self,
+        proposal_id: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, usize> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getProposalVoteCount")
+            .argument(&proposal_id)
+            .original_result()
+    }
+
+    pub fn governance_quorum(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, usize> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getGovernanceQuorum")
+            .original_result()
+    }
+
+    pub fn voting_period_seconds(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, u64> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getVotingPeriodSeconds")
+            .original_result()
+    }
+
+    pub fn proposals<
+        Arg0: ProxyArg<u64>,
+    >(
+        self,
+        proposal_id: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, AdminActionProposal<Env::Api>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getProposal")
+            .argument(&proposal_id)
+            .original_result()
+    }
+
+    /// Increasing the delay takes effect immediately, 
+    /// while decreasing it goes through the current timelock. 
+    pub fn set_timelock_delay<
+        Arg0: ProxyArg<u64>,
+    >(
+        self,
+        delay_seconds: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("setTimelockDelay")
+            .argument(&delay_seconds)
+            .original_result()
+    }
+
+    pub fn cancel_timelocked_action<
+        Arg0: ProxyArg<u64>,
+    >(
+        self,
+        action_id: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("cancelTimelockedAction")
+            .argument(&action_id)
+            .original_result()
+    }
+
+    pub fn timelock_delay_seconds(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, u64> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getTimelockDelay")
+            .original_result()
+    }
+
+    pub fn timelocked_actions<
+        Arg0: ProxyArg<u64>,
+    >(
+        self,
+        action_id: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, TimelockedAction<Env::Api>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getTimelockedAction")
+            .argument(&action_id)
+            .original_result()
+    }
+
+    /// Only the owner can grant the role admin role. 
+    /// Role admins can grant every other role. 
+    pub fn grant_role<
+        Arg0: ProxyArg<Role>,
+        Arg1: ProxyArg<ManagedAddress<Env::Api>>,
+    >(
+        self,
+        role: Arg0,
+        address: Arg1,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("grantRole")
+            .argument(&role)
+            .argument(&address)
+            .original_result()
+    }
+
+    pub fn revoke_role<
+        Arg0: ProxyArg<Role>,
+        Arg1: ProxyArg<ManagedAddress<Env::Api>>,
+    >(
+        self,
+        role: Arg0,
+        address: Arg1,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("revokeRole")
+            .argument(&role)
+            .argument(&address)
+            .original_result()
+    }
+
+    /// Lets the owner and the pausers pause the contract. Only the owner can unpause it. 
+    pub fn emergency_pause(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("emergencyPause")
+            .original_result()
+    }
+
+    pub fn has_role<
+        Arg0: ProxyArg<Role>,
+        Arg1: ProxyArg<ManagedAddress<Env::Api>>,
+    >(
+        self,
+        role: Arg0,
+        address: Arg1,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, bool> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("hasRole")
+            .argument(&role)
+            .argument(&address)
+            .original_result()
+    }
+
+    pub fn get_role_members<
+        Arg0: ProxyArg<Role>,
+    >(
+        self,
+        role: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, MultiValueEncoded<Env::Api, ManagedAddress<Env::Api>>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getRoleMembers")
+            .argument(&role)
+            .original_result()
+    }
+
+    pub fn storage_version(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, u32> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getStorageVersion")
+            .original_result()
+    }
+
+    /// Rounds moving more than `max_deviation_bps` from the previous round are held 
+    /// until a round within `max_deviation_bps` of the held price confirms them 
+    /// during `confirmation_window_seconds`, or until the owner releases them. 
+    /// A deviation of 0 disables the circuit breaker. 
+    pub fn set_circuit_breaker<
+        Arg0: ProxyArg<u64>,
+        Arg1: ProxyArg<u64>,
+    >(
+        self,
+        max_deviation_bps: Arg0,
+        confirmation_window_seconds: Arg1,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("setCircuitBreaker")
+            .argument(&max_deviation_bps)
+            .argument(&confirmation_window_seconds)
+            .original_result()
+    }
+
+    /// Publishes the held price of the pair as a new round. 
+    pub fn release_held_price<
+        Arg0: ProxyArg<ManagedBuffer<Env::Api>>,
+        Arg1: ProxyArg<ManagedBuffer<Env::Api>>,
+    >(
+        self,
+        from: Arg0,
+        to: Arg1,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("releaseHeldPrice")
+            .argument(&from)
+            .argument(&to)
+            .original_result()
+    }
+
+    pub fn get_held_price<
+        Arg0: ProxyArg<ManagedBuffer<Env::Api>>,
+        Arg1: ProxyArg<ManagedBuffer<Env::Api>>,
+    >(
+        self,
+        from: Arg0,
+        to: Arg1,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, OptionalValue<TimestampedPrice<Env::Api>>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getHeldPrice")
+            .argument(&from)
+            .argument(&to)
+            .original_result()
+    }
+
+    pub fn circuit_breaker_config(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, CircuitBreakerConfig> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getCircuitBreakerConfig")
+            .original_result()
+    }
+
+    /// Incident response when the oracles cannot submit. Not subject to the timelock. 
+    /// The manual price is served until it expires or the next round is created for the pair. 
+    pub fn set_manual_price<
+        Arg0: ProxyArg<ManagedBuffer<Env::Api>>,
+        Arg1: ProxyArg<ManagedBuffer<Env::Api>>,
+        Arg2: ProxyArg<BigUint<Env::Api>>,
+        Arg3: ProxyArg<u64>,
+    >(
+        self,
+        from: Arg0,
+        to: Arg1,
+        price: Arg2,
+        expires_at: Arg3,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("setManualPrice")
+            .argument(&from)
+            .argument(&to)
+            .argument(&price)
+            .argument(&expires_at)
+            .original_result()
+    }
+
+    pub fn clear_manual_price<
+        Arg0: ProxyArg<ManagedBuffer<Env::Api>>,
+        Arg1: ProxyArg<ManagedBuffer<Env::Api>>,
+    >(
+        self,
+        from: Arg0,
+        to: Arg1,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("clearManualPrice")
+            .argument(&from)
+            .argument(&to)
+            .original_result()
+    }
+
+    /// Tells whether `latestPriceFeed` currently serves the oracles' price or a manual price. 
+    pub fn latest_price_source<
+        Arg0: ProxyArg<ManagedBuffer<Env::Api>>,
+        Arg1: ProxyArg<ManagedBuffer<Env::Api>>,
+    >(
+        self,
+        from: Arg0,
+        to: Arg1,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, PriceSource> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("latestPriceSource")
+            .argument(&from)
+            .argument(&to)
+            .original_result()
+    }
+
+    pub fn get_manual_price<
+        Arg0: ProxyArg<ManagedBuffer<Env::Api>>,
+        Arg1: ProxyArg<ManagedBuffer<Env::Api>>,
+    >(
+        self,
+        from: Arg0,
+        to: Arg1,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, OptionalValue<ManualPrice<Env::Api>>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getManualPrice")
+            .argument(&from)
+            .argument(&to)
+            .original_result()
+    }
+
+    /// The fallback must be another price aggregator, deployed in the same shard, 
+    /// as it is read through a synchronous call. 
+    /// The local price feed is considered stale once it is older than `max_age_seconds`. 
+    pub fn set_pair_fallback<
+        Arg0: ProxyArg<ManagedBuffer<Env::Api>>,
+        Arg1: ProxyArg<ManagedBuffer<Env::Api>>,
+        Arg2: ProxyArg<ManagedAddress<Env::Api>>,
+        Arg3: ProxyArg<u64>,
+    >(
+        self,
+        from: Arg0,
+        to: Arg1,
+        address: Arg2,
+        max_age_seconds: Arg3,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("setPairFallback")
+            .argument(&from)
+            .argument(&to)
+            .argument(&address)
+            .argument(&max_age_seconds)
+            .original_result()
+    }
+
+    pub fn clear_pair_fallback<
+        Arg0: ProxyArg<ManagedBuffer<Env::Api>>,
+        Arg1: ProxyArg<ManagedBuffer<Env::Api>>,
+    >(
+        self,
+        from: Arg0,
+        to: Arg1,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("clearPairFallback")
+            .argument(&from)
+            .argument(&to)
+            .original_result()
+    }
+
+    pub fn get_pair_fallback<
+        Arg0: ProxyArg<ManagedBuffer<Env::Api>>,
+        Arg1: ProxyArg<ManagedBuffer<Env::Api>>,
+    >(
+        self,
+        from: Arg0,
+        to: Arg1,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, OptionalValue<PairFallback<Env::Api>>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getPairFallback")
+            .argument(&from)
+            .argument(&to)
+            .original_result()
+    }
+
+    /// Returns `(roundId, answer, startedAt, updatedAt, answeredInRound)`. 
+    /// An active manual price is returned with the id of the last round. 
+    pub fn latest_round_data_v3<
+        Arg0: ProxyArg<ManagedBuffer<Env::Api>>,
+        Arg1: ProxyArg<ManagedBuffer<Env::Api>>,
+    >(
+        self,
+        from: Arg0,
+        to: Arg1,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, MultiValue5<u32, BigUint<Env::Api>, u64, u64, u32>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("latestRoundDataV3")
+            .argument(&from)
+            .argument(&to)
+            .original_result()
+    }
+
+    /// Returns `(roundId, answer, startedAt, updatedAt, answeredInRound)` for a past round. 
+    pub fn get_round_data<
+        Arg0: ProxyArg<ManagedBuffer<Env::Api>>,
+        Arg1: ProxyArg<ManagedBuffer<Env::Api>>,
+        Arg2: ProxyArg<u32>,
+    >(
+        self,
+        from: Arg0,
+        to: Arg1,
+        round_id: Arg2,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, MultiValue5<u32, BigUint<Env::Api>, u64, u64, u32>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getRoundData")
+            .argument(&from)
+            .argument(&to)
+            .argument(&round_id)
+            .original_result()
+    }
+
+    pub fn decimals<
+        Arg0: ProxyArg<ManagedBuffer<Env::Api>>,
+        Arg1: ProxyArg<ManagedBuffer<Env::Api>>,
+    >(
+        self,
+        from: Arg0,
+        to: Arg1,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, u8> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("decimals")
+            .argument(&from)
+            .argument(&to)
+            .original_result()
+    }
+
+    /// Formatted as `FROM / TO`, like the Chainlink feeds. 
+    pub fn description<
+        Arg0: ProxyArg<ManagedBuffer<Env::Api>>,
+        Arg1: ProxyArg<ManagedBuffer<Env::Api>>,
+    >(
+        self,
+        from: Arg0,
+        to: Arg1,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ManagedBuffer<Env::Api>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("description")
+            .argument(&from)
+            .argument(&to)
+            .original_result()
+    }
+
+    pub fn version(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, u64> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("version")
+            .original_result()
+    }
+
+    /// The state of the round in progress for the pair, and the median it would have if it were created now, 
+    /// leaving out the submissions the pair's maximum confidence would. 
+    /// `seconds_until_discarded` is 0 when the round already expired and will be discarded on the next submission. 
+    pub fn get_pending_round<
+        Arg0: ProxyArg<ManagedBuffer<Env::Api>>,
+        Arg1: ProxyArg<ManagedBuffer<Env::Api>>,
+    >(
+        self,
+        from: Arg0,
+        to: Arg1,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, PendingRound<Env::Api>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getPendingRound")
+            .argument(&from)
+            .argument(&to)
+            .original_result()
+    }
+
+    pub fn has_submitted<
+        Arg0: ProxyArg<ManagedBuffer<Env::Api>>,
+        Arg1: ProxyArg<ManagedBuffer<Env::Api>>,
+        Arg2: ProxyArg<ManagedAddress<Env::Api>>,
+    >(
+        self,
+        from: Arg0,
+        to: Arg1,
+        oracle: Arg2,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, bool> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("hasSubmitted")
+            .argument(&from)
+            .argument(&to)
+            .argument(&oracle)
+            .original_result()
+    }
+
+    /// A new round is needed when the price moved more than `deviation_threshold_bps` 
+    /// from the last round, or when the last round is at least `heartbeat_seconds` old. 
+    /// A value of 0 disables the respective condition, but at least one of them must be enabled. 
+    /// When `enforced`, submissions starting a round that is not needed are rejected. 
+    pub fn set_pair_update_policy<
+        Arg0: ProxyArg<ManagedBuffer<Env::Api>>,
+        Arg1: ProxyArg<ManagedBuffer<Env::Api>>,
+        Arg2: ProxyArg<u64>,
+        Arg3: ProxyArg<u64>,
+        Arg4: ProxyArg<bool>,
+    >(
+        self,
+        from: Arg0,
+        to: Arg1,
+        deviation_threshold_bps: Arg2,
+        heartbeat_seconds: Arg3,
+        enforced: Arg4,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("setPairUpdatePolicy")
+            .argument(&from)
+            .argument(&to)
+            .argument(&deviation_threshold_bps)
+            .argument(&heartbeat_seconds)
+            .argument(&enforced)
+            .original_result()
+    }
+
+    pub fn clear_pair_update_policy<
+        Arg0: ProxyArg<ManagedBuffer<Env::Api>>,
+        Arg1: ProxyArg<ManagedBuffer<Env::Api>>,
+    >(
+        self,
+        from: Arg0,
+        to: Arg1,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("clearPairUpdatePolicy")
+            .argument(&from)
+            .argument(&to)
+            .original_result()
+    }
+
+    /// Tells oracles whether submitting `candidate_price` would be useful. 
+    /// Always true for pairs without an update policy or without rounds. 
+    pub fn should_update<
+        Arg0: ProxyArg<ManagedBuffer<Env::Api>>,
+        Arg1: ProxyArg<ManagedBuffer<Env::Api>>,
+        Arg2: ProxyArg<BigUint<Env::Api>>,
+    >(
+        self,
+        from: Arg0,
+        to: Arg1,
+        candidate_price: Arg2,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, bool> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("shouldUpdate")
+            .argument(&from)
+            .argument(&to)
+            .argument(&candidate_price)
+            .original_result()
+    }
+
+    pub fn get_pair_update_policy<
+        Arg0: ProxyArg<ManagedBuffer<Env::Api>>,
+        Arg1: ProxyArg<ManagedBuffer<Env::Api>>,
+    >(
+        self,
+        from: Arg0,
+        to: Arg1,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, OptionalValue<UpdatePolicy>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getPairUpdatePolicy")
+            .argument(&from)
+            .argument(&to)
+            .original_result()
+    }
+
+    /// Defines a composite feed as the weighted sum of existing pairs. 
+    /// Weights have `BASKET_WEIGHT_DECIMALS` decimals, e.g. 10^18 for one unit of the constituent. 
+    pub fn create_basket<
+        Arg0: ProxyArg<ManagedBuffer<Env::Api>>,
+        Arg1: ProxyArg<u8>,
+        Arg2: ProxyArg<MultiValueEncoded<Env::Api, MultiValue3<ManagedBuffer<Env::Api>, ManagedBuffer<Env::Api>, BigUint<Env::Api>>>>,
+    >(
+        self,
+        name: Arg0,
+        decimals: Arg1,
+        constituents: Arg2,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("createBasket")
+            .argument(&name)
+            .argument(&decimals)
+            .argument(&constituents)
+            .original_result()
+    }
+
+    pub fn remove_basket<
+        Arg0: ProxyArg<ManagedBuffer<Env::Api>>,
+    >(
+        self,
+        name: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("removeBasket")
+            .argument(&name)
+            .original_result()
+    }
+
+    /// Computed from the latest price feed of every constituent, which is its manual price while one is active. 
+    /// The timestamp is the one of the oldest constituent price. 
+    pub fn get_basket_price<
+        Arg0: ProxyArg<ManagedBuffer<Env::Api>>,
+    >(
+        self,
+        name: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, BasketPrice<Env::Api>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getBasketPrice")
+            .argument(&name)
+            .original_result()
+    }
+
+    pub fn get_basket<
+        Arg0: ProxyArg<ManagedBuffer<Env::Api>>,
+    >(
+        self,
+        name: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, Basket<Env::Api>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getBasket")
+            .argument(&name)
+            .original_result()
+    }
+
+    pub fn get_basket_names(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, MultiValueEncoded<Env::Api, ManagedBuffer<Env::Api>>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getBasketNames")
+            .original_result()
+    }
+
+    /// Replaces the tracked half-lives, in seconds. Every EMA series restarts. 
+    pub fn set_ema_half_lives<
+        Arg0: ProxyArg<MultiValueEncoded<Env::Api, u64>>,
+    >(
+        self,
+        half_lives: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("setEmaHalfLives")
+            .argument(&half_lives)
+            .original_result()
+    }
+
+    /// Sets the number of rounds used for the realized volatility. The series restarts. 
+    pub fn set_volatility_window<
+        Arg0: ProxyArg<usize>,
+    >(
+        self,
+        window: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("setVolatilityWindow")
+            .argument(&window)
+            .original_result()
+    }
+
+    pub fn get_ema<
+        Arg0: ProxyArg<ManagedBuffer<Env::Api>>,
+        Arg1: ProxyArg<ManagedBuffer<Env::Api>>,
+        Arg2: ProxyArg<u64>,
+    >(
+        self,
+        from: Arg0,
+        to: Arg1,
+        half_life: Arg2,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, OptionalValue<EmaState<Env::Api>>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getEma")
+            .argument(&from)
+            .argument(&to)
+            .argument(&half_life)
+            .original_result()
+    }
+
+    /// The root mean square of the relative price changes over the last rounds of the pair. 
+    pub fn get_realized_volatility<
+        Arg0: ProxyArg<ManagedBuffer<Env::Api>>,
+        Arg1: ProxyArg<ManagedBuffer<Env::Api>>,
+    >(
+        self,
+        from: Arg0,
+        to: Arg1,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, RealizedVolatility<Env::Api>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getRealizedVolatility")
+            .argument(&from)
+            .argument(&to)
+            .original_result()
+    }
+
+    pub fn ema_half_lives(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ManagedVec<Env::Api, u64>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getEmaHalfLives")
+            .original_result()
+    }
+
+    pub fn volatility_window(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, usize> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getVolatilityWindow")
+            .original_result()
+    }
+
+    /// Can only be changed before any fee is collected in the current epoch, so that every epoch is paid in one token. 
+    pub fn set_access_fee_token<
+        Arg0: ProxyArg<EgldOrEsdtTokenIdentifier<Env::Api>>,
+    >(
+        self,
+        token: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("setAccessFeeToken")
+            .argument(&token)
+            .original_result()
+    }
+
+    /// A zero amount disables paid access for the pair. 
+    pub fn set_pair_access_fee<
+        Arg0: ProxyArg<ManagedBuffer<Env::Api>>,
+        Arg1: ProxyArg<ManagedBuffer<Env::Api>>,
+        Arg2: ProxyArg<BigUint<Env::Api>>,
+    >(
+        self,
+        from: Arg0,
+        to: Arg1,
+        amount: Arg2,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("setPairAccessFee")
+            .argument(&from)
+            .argument(&to)
+            .argument(&amount)
+            .original_result()
+    }
+
+    /// Pays the caller's share of the fees of each given epoch, in proportion to its accepted submissions. 
+    /// Only past epochs can be claimed. 
+    pub fn claim_revenue<
+        Arg0: ProxyArg<MultiValueEncoded<Env::Api, u64>>,
+    >(
+        self,
+        epochs: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("claimRevenue")
+            .argument(&epochs)
+            .original_result()
+    }
+
+    /// Fees of an epoch without accepted submissions have no oracle to go to. 
+    pub fn withdraw_unclaimable_revenue<
+        Arg0: ProxyArg<u64>,
+    >(
+        self,
+        epoch: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("withdrawUnclaimableRevenue")
+            .argument(&epoch)
+            .original_result()
+    }
+
+    pub fn get_claimable_revenue<
+        Arg0: ProxyArg<ManagedAddress<Env::Api>>,
+        Arg1: ProxyArg<u64>,
+    >(
+        self,
+        oracle: Arg0,
+        epoch: Arg1,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, BigUint<Env::Api>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getClaimableRevenue")
+            .argument(&oracle)
+            .argument(&epoch)
+            .original_result()
+    }
+
+    /// Zero when paid access is disabled for the pair. 
+    pub fn get_pair_access_fee<
+        Arg0: ProxyArg<ManagedBuffer<Env::Api>>,
+        Arg1: ProxyArg<ManagedBuffer<Env::Api>>,
+    >(
+        self,
+        from: Arg0,
+        to: Arg1,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, BigUint<Env::Api>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getPairAccessFee")
+            .argument(&from)
+            .argument(&to)
+            .original_result()
+    }
+
+    pub fn access_fee_token(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, EgldOrEsdtTokenIdentifier<Env::Api>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getAccessFeeToken")
+            .original_result()
+    }
+
+    pub fn epoch_revenue<
+        Arg0: ProxyArg<u64>,
+    >(
+        self,
+        epoch: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, BigUint<Env::Api>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getEpochRevenue")
+            .argument(&epoch)
+            .original_result()
+    }
+
+    /// Submissions with a confidence interval wider than `max_confidence_bps` of their price 
+    /// are left out of the median, unless every submission of the round is. 
+    pub fn set_pair_max_confidence<
+        Arg0: ProxyArg<ManagedBuffer<Env::Api>>,
+        Arg1: ProxyArg<ManagedBuffer<Env::Api>>,
+        Arg2: ProxyArg<u64>,
+    >(
+        self,
+        from: Arg0,
+        to: Arg1,
+        max_confidence_bps: Arg2,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("setPairMaxConfidence")
+            .argument(&from)
+            .argument(&to)
+            .argument(&max_confidence_bps)
+            .original_result()
+    }
+
+    pub fn clear_pair_max_confidence<
+        Arg0: ProxyArg<ManagedBuffer<Env::Api>>,
+        Arg1: ProxyArg<ManagedBuffer<Env::Api>>,
+    >(
+        self,
+        from: Arg0,
+        to: Arg1,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("clearPairMaxConfidence")
+            .argument(&from)
+            .argument(&to)
+            .original_result()
+    }
+
+    /// The median of the confidence intervals submitted for the last round of the pair, 
+    /// if any oracle attached one. 
+    pub fn get_round_confidence<
+        Arg0: ProxyArg<ManagedBuffer<Env::Api>>,
+        Arg1: ProxyArg<ManagedBuffer<Env::Api>>,
+    >(
+        self,
+        from: Arg0,
+        to: Arg1,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, OptionalValue<RoundConfidence<Env::Api>>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getRoundConfidence")
+            .argument(&from)
+            .argument(&to)
+            .original_result()
+    }
+
+    /// Zero when submissions are not filtered by confidence. 
+    pub fn get_pair_max_confidence<
+        Arg0: ProxyArg<ManagedBuffer<Env::Api>>,
+        Arg1: ProxyArg<ManagedBuffer<Env::Api>>,
+    >(
+        self,
+        from: Arg0,
+        to: Arg1,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, u64> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getPairMaxConfidence")
+            .argument(&from)
+            .argument(&to)
+            .original_result()
+    }
+
+    /// Links the pair to the tokens it prices, for the legs that are ESDTs. 
+    pub fn set_pair_metadata<
+        Arg0: ProxyArg<ManagedBuffer<Env::Api>>,
+        Arg1: ProxyArg<ManagedBuffer<Env::Api>>,
+        Arg2: ProxyArg<Option<TokenIdentifier<Env::Api>>>,
+        Arg3: ProxyArg<Option<TokenIdentifier<Env::Api>>>,
+        Arg4: ProxyArg<ManagedBuffer<Env::Api>>,
+        Arg5: ProxyArg<ManagedBuffer<Env::Api>>,
+    >(
+        self,
+        from: Arg0,
+        to: Arg1,
+        from_token: Arg2,
+        to_token: Arg3,
+        description: Arg4,
+        category: Arg5,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("setPairMetadata")
+            .argument(&from)
+            .argument(&to)
+            .argument(&from_token)
+            .argument(&to_token)
+            .argument(&description)
+            .argument(&category)
+            .original_result()
+    }
+
+    pub fn clear_pair_metadata<
+        Arg0: ProxyArg<ManagedBuffer<Env::Api>>,
+        Arg1: ProxyArg<ManagedBuffer<Env::Api>>,
+    >(
+        self,
+        from: Arg0,
+        to: Arg1,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("clearPairMetadata")
+            .argument(&from)
+            .argument(&to)
+            .original_result()
+    }
+
+    pub fn get_pair_metadata<
+        Arg0: ProxyArg<ManagedBuffer<Env::Api>>,
+        Arg1: ProxyArg<ManagedBuffer<Env::Api>>,
+    >(
+        self,
+        from: Arg0,
+        to: Arg1,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, OptionalValue<PairMetadata<Env::Api>>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getPairMetadata")
+            .argument(&from)
+            .argument(&to)
+            .original_result()
+    }
+
+    /// The pairs whose metadata references the token, on either leg. 
+    pub fn get_pairs_for_token<
+        Arg0: ProxyArg<TokenIdentifier<Env::Api>>,
+    >(
+        self,
+        token: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, MultiValueEncoded<Env::Api, MultiValue2<ManagedBuffer<Env::Api>, ManagedBuffer<Env::Api>>>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getPairsForToken")
+            .argument(&token)
+            .original_result()
+    }
+
+    /// Requests price notifications for the pair, delivered by calling `endpoint` with the `PriceFeed`. 
+    /// The subscription fee, if configured, is paid here and refunded if the owner rejects the subscription. 
+    pub fn subscribe<
+        Arg0: ProxyArg<ManagedBuffer<Env::Api>>,
+        Arg1: ProxyArg<ManagedBuffer<Env::Api>>,
+        Arg2: ProxyArg<ManagedBuffer<Env::Api>>,
+        Arg3: ProxyArg<u64>,
+    >(
+        self,
+        from: Arg0,
+        to: Arg1,
+        endpoint: Arg2,
+        gas_limit: Arg3,
+    ) -> TxTypedCall<Env, From, To, (), Gas, ()> {
+        self.wrapped_tx
+            .raw_call("subscribe")
+            .argument(&from)
+            .argument(&to)
+            .argument(&endpoint)
+            .argument(&gas_limit)
+            .original_result()
+    }
+
+    /// Sends the subscription fee to the owner. 
+    pub fn approve_subscription<
+        Arg0: ProxyArg<ManagedBuffer<Env::Api>>,
+        Arg1: ProxyArg<ManagedBuffer<Env::Api>>,
+        Arg2: ProxyArg<ManagedAddress<Env::Api>>,
+    >(
+        self,
+        from: Arg0,
+        to: Arg1,
+        consumer: Arg2,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("approveSubscription")
+            .argument(&from)
+            .argument(&to)
+            .argument(&consumer)
+            .original_result()
+    }
+
+    /// Removes a pending or approved subscription, refunding the fee of a pending one. 
+    pub fn remove_subscription<
+        Arg0: ProxyArg<ManagedBuffer<Env::Api>>,
+        Arg1: ProxyArg<ManagedBuffer<Env::Api>>,
+        Arg2: ProxyArg<ManagedAddress<Env::Api>>,
+    >(
+        self,
+        from: Arg0,
+        to: Arg1,
+        consumer: Arg2,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("removeSubscription")
+            .argument(&from)
+            .argument(&to)
+            .argument(&consumer)
+            .original_result()
+    }
+
+    pub fn unsubscribe<
+        Arg0: ProxyArg<ManagedBuffer<Env::Api>>,
+        Arg1: ProxyArg<ManagedBuffer<Env::Api>>,
+    >(
+        self,
+        from: Arg0,
+        to: Arg1,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("unsubscribe")
+            .argument(&from)
+            .argument(&to)
+            .original_result()
+    }
+
+    /// A zero amount removes the fee. 
+    pub fn set_subscription_fee<
+        Arg0: ProxyArg<EgldOrEsdtTokenIdentifier<Env::Api>>,
+        Arg1: ProxyArg<BigUint<Env::Api>>,
+    >(
+        self,
+        token: Arg0,
+        amount: Arg1,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("setSubscriptionFee")
+            .argument(&token)
+            .argument(&amount)
+            .original_result()
+    }
+
+    pub fn get_subscription<
+        Arg0: ProxyArg<ManagedBuffer<Env::Api>>,
+        Arg1: ProxyArg<ManagedBuffer<Env::Api>>,
+        Arg2: ProxyArg<ManagedAddress<Env::Api>>,
+    >(
+        self,
+        from: Arg0,
+        to: Arg1,
+        consumer: Arg2,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, OptionalValue<Subscription<Env::Api>>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getSubscription")
+            .argument(&from)
+            .argument(&to)
+            .argument(&consumer)
+            .original_result()
+    }
+
+    pub fn get_subscribers<
+        Arg0: ProxyArg<ManagedBuffer<Env::Api>>,
+        Arg1: ProxyArg<ManagedBuffer<Env::Api>>,
+    >(
+        self,
+        from: Arg0,
+        to: Arg1,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, MultiValueEncoded<Env::Api, ManagedAddress<Env::Api>>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getSubscribers")
+            .argument(&from)
+            .argument(&to)
+            .original_result()
+    }
+
+    pub fn subscription_fee(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, EgldOrEsdtTokenPayment<Env::Api>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getSubscriptionFee")
+            .original_result()
+    }
+}
+
+#[type_abi]
+#[derive(NestedEncode, NestedDecode, TopEncode, TopDecode, Debug, PartialEq, Eq)]
+pub struct RoundWindow {
+    pub window_seconds: u64,
+    pub min_submissions: usize,
+}
+
+#[type_abi]
+#[derive(NestedEncode, NestedDecode, TopEncode, TopDecode)]
+pub struct PriceFeed<Api>
+where
+    Api: ManagedTypeApi,
+{
+    pub round_id: u32,
+    pub from: ManagedBuffer<Api>,
+    pub to: ManagedBuffer<Api>,
+    pub timestamp: u64,
+    pub price: BigUint<Api>,
+    pub decimals: u8,
+}
+
+#[type_abi]
+#[derive(TopEncode, TopDecode, Debug, PartialEq, Eq)]
+pub enum PriceSource {
+    Oracles,
+    Manual,
+    Fallback,
+}
+
+#[type_abi]
+#[derive(NestedEncode, NestedDecode, TopEncode, TopDecode, Debug, PartialEq, Eq)]
+pub struct OracleApplication<Api>
+where
+    Api: ManagedTypeApi,
+{
+    pub operator_name: ManagedBuffer<Api>,
+    pub contact: ManagedBuffer<Api>,
+    pub stake_token: EgldOrEsdtTokenIdentifier<Api>,
+    pub stake_amount: BigUint<Api>,
+    pub timestamp: u64,
+}
+
+#[rustfmt::skip]
+#[type_abi]
+#[derive(NestedEncode, NestedDecode, TopEncode, TopDecode, Clone)]
+pub enum AdminAction<Api>
+where
+    Api: ManagedTypeApi,
+{
+    AddOracles {
+        oracles: ManagedVec<Api, ManagedAddress<Api>>,
+    },
+    RemoveOracles {
+        submission_count: usize,
+        oracles: ManagedVec<Api, ManagedAddress<Api>>,
+    },
+    SetSubmissionCount {
+        submission_count: usize,
+    },
+    ChangeAmounts {
+        staking_amount: BigUint<Api>,
+        slash_amount: BigUint<Api>,
+    },
+    SetPairDecimals {
+        from: ManagedBuffer<Api>,
+        to: ManagedBuffer<Api>,
+        decimals: u8,
+    },
+    SetTimelockDelay {
+        delay_seconds: u64,
+    },
+    SetStakingTokenWeight {
+        token: EgldOrEsdtTokenIdentifier<Api>,
+        weight: u64,
+    },
+    RemoveStakingToken {
+        token: EgldOrEsdtTokenIdentifier<Api>,
+    },
+}
+
+#[type_abi]
+#[derive(NestedEncode, NestedDecode, TopEncode, TopDecode)]
+pub struct AdminActionProposal<Api>
+where
+    Api: ManagedTypeApi,
+{
+    pub action: AdminAction<Api>,
+    pub proposer: ManagedAddress<Api>,
+    pub deadline: u64,
+}
+
+#[type_abi]
+#[derive(NestedEncode, NestedDecode, TopEncode, TopDecode)]
+pub struct TimelockedAction<Api>
+where
+    Api: ManagedTypeApi,
+{
+    pub action: AdminAction<Api>,
+    pub execution_timestamp: u64,
+}
+
+#[type_abi]
+#[derive(NestedEncode, NestedDecode, TopEncode, TopDecode, Clone, Copy, PartialEq, Eq, Debug)]
+pub enum Role {
+    RoleAdmin,
+    PairManager,
+    OracleManager,
+    Pauser,
+}
+
+#[type_abi]
+#[derive(TopEncode, TopDecode, Debug, PartialEq, Eq)]
+pub struct TimestampedPrice<Api>
+where
+    Api: ManagedTypeApi,
+{
+    pub price: BigUint<Api>,
+    pub timestamp: u64,
+    pub decimals: u8,
+}
+
+#[type_abi]
+#[derive(NestedEncode, NestedDecode, TopEncode, TopDecode, Debug, PartialEq, Eq)]
+pub struct CircuitBreakerConfig {
+    pub max_deviation_bps: u64,
+    pub confirmation_window_seconds: u64,
+}
+
+#[type_abi]
+#[derive(NestedEncode, NestedDecode, TopEncode, TopDecode, Debug, PartialEq, Eq)]
+pub struct ManualPrice<Api>
+where
+    Api: ManagedTypeApi,
+{
+    pub price: BigUint<Api>,
+    pub timestamp: u64,
+    pub decimals: u8,
+    pub expires_at: u64,
+}
+
+#[type_abi]
+#[derive(NestedEncode, NestedDecode, TopEncode, TopDecode, Debug, PartialEq, Eq)]
+pub struct PairFallback<Api>
+where
+    Api: ManagedTypeApi,
+{
+    pub address: ManagedAddress<Api>,
+    pub max_age_seconds: u64,
+}
+
+#[type_abi]
+#[derive(TopEncode, TopDecode, Debug, PartialEq, Eq)]
+pub struct PendingRound<Api>
+where
+    Api: ManagedTypeApi,
+{
+    pub submission_count: usize,
+    pub first_submission_timestamp: u64,
+    pub last_submission_timestamp: u64,
+    pub seconds_until_discarded: u64,
+    pub oracles: ManagedVec<Api, ManagedAddress<Api>>,
+    pub median: Option<BigUint<Api>>,
+}
+
+#[type_abi]
+#[derive(NestedEncode, NestedDecode, TopEncode, TopDecode, Debug, PartialEq, Eq)]
+pub struct UpdatePolicy {
+    pub deviation_threshold_bps: u64,
+    pub heartbeat_seconds: u64,
+    pub enforced: bool,
+}
+
+#[type_abi]
+#[derive(TopEncode, TopDecode, Debug, PartialEq, Eq)]
+pub struct BasketPrice<Api>
+where
+    Api: ManagedTypeApi,
+{
+    pub price: BigUint<Api>,
+    pub decimals: u8,
+    pub timestamp: u64,
+}
+
+#[type_abi]
+#[derive(NestedEncode, NestedDecode, TopEncode, TopDecode, Debug)]
+pub struct Basket<Api>
+where
+    Api: ManagedTypeApi,
+{
+    pub decimals: u8,
+    pub constituents: ManagedVec<Api, BasketConstituent<Api>>,
+}
+
+#[type_abi]
+#[derive(ManagedVecItem, NestedEncode, NestedDecode, TopEncode, TopDecode, Clone, Debug)]
+pub struct BasketConstituent<Api>
+where
+    Api: ManagedTypeApi,
+{
+    pub from: ManagedBuffer<Api>,
+    pub to: ManagedBuffer<Api>,
+    pub weight: BigUint<Api>,
+}
+
+#[type_abi]
+#[derive(NestedEncode, NestedDecode, TopEncode, TopDecode, Debug, PartialEq, Eq)]
+pub struct EmaState<Api>
+where
+    Api: ManagedTypeApi,
+{
+    pub value: BigUint<Api>,
+    pub decimals: u8,
+    pub timestamp: u64,
+}
+
+#[type_abi]
+#[derive(TopEncode, TopDecode, Debug, PartialEq, Eq)]
+pub struct RealizedVolatility<Api>
+where
+    Api: ManagedTypeApi,
+{
+    pub volatility: BigUint<Api>,
+    pub sample_count: usize,
+}
+
+#[type_abi]
+#[derive(NestedEncode, NestedDecode, TopEncode, TopDecode, Debug, PartialEq, Eq)]
+pub struct RoundConfidence<Api>
+where
+    Api: ManagedTypeApi,
+{
+    pub timestamp: u64,
+    pub confidence: BigUint<Api>,
+    pub sample_count: usize,
+}
+
+#[type_abi]
+#[derive(NestedEncode, NestedDecode, TopEncode, TopDecode, Debug, PartialEq, Eq)]
+pub struct PairMetadata<Api>
+where
+    Api: ManagedTypeApi,
+{
+    pub from_token: Option<TokenIdentifier<Api>>,
+    pub to_token: Option<TokenIdentifier<Api>>,
+    pub description: ManagedBuffer<Api>,
+    pub category: ManagedBuffer<Api>,
+}
+
+#[type_abi]
+#[derive(NestedEncode, NestedDecode, TopEncode, TopDecode, Debug, PartialEq, Eq)]
+pub struct Subscription<Api>
+where
+    Api: ManagedTypeApi,
+{
+    pub endpoint: ManagedBuffer<Api>,
+    pub gas_limit: u64,
+    pub approved: bool,
+    pub consecutive_failures: u32,
+    pub fee: EgldOrEsdtTokenPayment<Api>,
 }
 
 #[type_abi]
@@ -1,6 +1,7 @@
 use multiversx_price_aggregator_sc::{
     price_aggregator_data::{OracleStatus, TokenPair},
-    ContractObj, PriceAggregator,
+    storage::StorageModule,
+    ContractObj,
 };
 
 use multiversx_sc_scenario::imports::*;
@@ -145,6 +146,7 @@ impl PriceAggregatorTestState {
                 submission_timestamp,
                 price,
                 DECIMALS,
+                OptionalValue::<BigUint<StaticApi>>::None,
             )
             .run();
     }
@@ -203,7 +205,8 @@ fn test_price_aggregator_submit() {
                         .unwrap(),
                     OracleStatus {
                         total_submissions: 1,
-                        accepted_submissions: 1
+                        accepted_submissions: 1,
+                        replaced_submissions: 0
                     }
                 );
             }
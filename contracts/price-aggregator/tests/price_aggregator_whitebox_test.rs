@@ -1,11 +1,11 @@
 use multiversx_price_aggregator_sc::{
     price_aggregator_data::{OracleStatus, TimestampedPrice, TokenPair},
+    staking::{EndpointWrappers as StakingEndpointWrappers, StakingModule},
+    storage::StorageModule,
+    storage_migration::{OracleStatusV2, StorageMigrationModule, STORAGE_VERSION},
     PriceAggregator, MAX_ROUND_DURATION_SECONDS,
 };
-use multiversx_sc_modules::{
-    pause::EndpointWrappers as PauseEndpointWrappers,
-    staking::EndpointWrappers as StakingEndpointWrappers,
-};
+use multiversx_sc_modules::pause::EndpointWrappers as PauseEndpointWrappers;
 use multiversx_sc_scenario::imports::*;
 
 pub const DECIMALS: u8 = 0;
@@ -66,6 +66,7 @@ fn test_price_aggregator_submit() {
                 99,
                 managed_biguint!(100),
                 DECIMALS,
+                OptionalValue::None,
             )
         },
         |r| r.assert_user_error("Contract is paused"),
@@ -89,6 +90,7 @@ fn test_price_aggregator_submit() {
                 10,
                 managed_biguint!(100),
                 DECIMALS,
+                OptionalValue::None,
             )
         },
         |r| {
@@ -107,6 +109,7 @@ fn test_price_aggregator_submit() {
                 95,
                 managed_biguint!(100),
                 DECIMALS,
+                OptionalValue::None,
             )
         },
     );
@@ -141,7 +144,8 @@ fn test_price_aggregator_submit() {
                 .unwrap(),
             OracleStatus {
                 total_submissions: 1,
-                accepted_submissions: 1
+                accepted_submissions: 1,
+                replaced_submissions: 0
             }
         );
     });
@@ -157,6 +161,7 @@ fn test_price_aggregator_submit() {
                 95,
                 managed_biguint!(100),
                 DECIMALS,
+                OptionalValue::None,
             )
         },
     );
@@ -168,7 +173,8 @@ fn test_price_aggregator_submit() {
                 .unwrap(),
             OracleStatus {
                 total_submissions: 2,
-                accepted_submissions: 1
+                accepted_submissions: 1,
+                replaced_submissions: 0
             }
         );
     });
@@ -213,6 +219,7 @@ fn test_price_aggregator_submit_round_ok() {
                 95,
                 managed_biguint!(10_000),
                 DECIMALS,
+                OptionalValue::None,
             )
         },
     );
@@ -231,6 +238,7 @@ fn test_price_aggregator_submit_round_ok() {
                 101,
                 managed_biguint!(11_000),
                 DECIMALS,
+                OptionalValue::None,
             )
         },
     );
@@ -246,6 +254,7 @@ fn test_price_aggregator_submit_round_ok() {
                 105,
                 managed_biguint!(12_000),
                 DECIMALS,
+                OptionalValue::None,
             )
         },
     );
@@ -319,6 +328,7 @@ fn test_price_aggregator_discarded_round() {
                 95,
                 managed_biguint!(10_000),
                 DECIMALS,
+                OptionalValue::None,
             )
         },
     );
@@ -337,6 +347,7 @@ fn test_price_aggregator_discarded_round() {
                 current_timestamp - 1,
                 managed_biguint!(11_000),
                 DECIMALS,
+                OptionalValue::None,
             )
         },
     );
@@ -415,6 +426,7 @@ fn test_price_aggregator_slashing() {
                 95,
                 managed_biguint!(10_000),
                 DECIMALS,
+                OptionalValue::None,
             )
         },
         |r| {
@@ -423,6 +435,97 @@ fn test_price_aggregator_slashing() {
     );
 }
 
+#[test]
+fn test_price_aggregator_upgrade_migrates_legacy_storage() {
+    let (mut world, oracles) = setup();
+    let price_aggregator_whitebox = WhiteboxContract::new(
+        PRICE_AGGREGATOR_ADDRESS_EXPR,
+        multiversx_price_aggregator_sc::contract_obj,
+    );
+
+    // rewrite the stake of the first oracle with the upstream staking layout,
+    // and the oracle statuses without the replaced submissions
+    world.whitebox_call(
+        &price_aggregator_whitebox,
+        ScCallStep::new().from(OWNER_ADDRESS_EXPR),
+        |sc| {
+            let mut legacy_oracle_status =
+                MapMapper::<DebugApi, ManagedAddress<DebugApi>, OracleStatusV2>::new(
+                    multiversx_sc::storage::StorageKey::new(b"oracle_status"),
+                );
+            sc.oracle_status().clear();
+            for oracle in &oracles {
+                let _ = legacy_oracle_status.insert(
+                    managed_address!(&oracle.to_address()),
+                    OracleStatusV2 {
+                        accepted_submissions: 2,
+                        total_submissions: 3,
+                    },
+                );
+            }
+
+            let oracle = managed_address!(&oracles[0].to_address());
+            let staking_token = EgldOrEsdtTokenIdentifier::egld();
+            let stake = sc.staked_token_amount(&oracle, &staking_token).take();
+            sc.legacy_staked_amount(&oracle).set(stake);
+            sc.legacy_total_slashed_amount()
+                .set(managed_biguint!(SLASH_AMOUNT));
+            sc.staking_token_weights().clear();
+            sc.storage_version().clear();
+        },
+    );
+
+    world.whitebox_call(
+        &price_aggregator_whitebox,
+        ScCallStep::new().from(OWNER_ADDRESS_EXPR),
+        |sc| sc.upgrade(),
+    );
+
+    world.whitebox_query(&price_aggregator_whitebox, |sc| {
+        let oracle = managed_address!(&oracles[0].to_address());
+        let staking_token = EgldOrEsdtTokenIdentifier::egld();
+
+        assert_eq!(sc.storage_version().get(), STORAGE_VERSION);
+        assert!(sc.legacy_staked_amount(&oracle).is_empty());
+        assert!(sc.legacy_total_slashed_amount().is_empty());
+        assert_eq!(
+            sc.staked_token_amount(&oracle, &staking_token).get(),
+            managed_biguint!(STAKE_AMOUNT)
+        );
+        assert_eq!(
+            sc.total_slashed_amount(&staking_token).get(),
+            managed_biguint!(SLASH_AMOUNT)
+        );
+        assert_eq!(
+            sc.get_staked_amount(&oracle),
+            managed_biguint!(STAKE_AMOUNT)
+        );
+        assert_eq!(
+            sc.oracle_status().get(&oracle).unwrap(),
+            OracleStatus {
+                accepted_submissions: 2,
+                total_submissions: 3,
+                replaced_submissions: 0
+            }
+        );
+    });
+
+    // upgrading an up to date contract leaves the state untouched
+    world.whitebox_call(
+        &price_aggregator_whitebox,
+        ScCallStep::new().from(OWNER_ADDRESS_EXPR),
+        |sc| sc.upgrade(),
+    );
+
+    world.whitebox_query(&price_aggregator_whitebox, |sc| {
+        let oracle = managed_address!(&oracles[0].to_address());
+        assert_eq!(
+            sc.get_staked_amount(&oracle),
+            managed_biguint!(STAKE_AMOUNT)
+        );
+    });
+}
+
 fn setup() -> (ScenarioWorld, Vec<AddressValue>) {
     // setup
     let mut world = world();
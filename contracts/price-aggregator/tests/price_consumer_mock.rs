@@ -0,0 +1,18 @@
+use multiversx_price_aggregator_sc::price_aggregator_data::PriceFeed;
+use multiversx_sc::imports::*;
+
+/// Stands in for a subscribed consumer contract, keeping the last notification it received.
+#[multiversx_sc::contract]
+pub trait PriceConsumerMock {
+    #[init]
+    fn init(&self) {}
+
+    #[endpoint(onPrice)]
+    fn on_price(&self, price_feed: PriceFeed<Self::Api>) {
+        self.last_price_feed().set(price_feed);
+    }
+
+    #[view(getLastPriceFeed)]
+    #[storage_mapper("last_price_feed")]
+    fn last_price_feed(&self) -> SingleValueMapper<PriceFeed<Self::Api>>;
+}
@@ -5,9 +5,10 @@
 ////////////////////////////////////////////////////
 
 // Init:                                 1
-// Endpoints:                           21
+// Upgrade:                              1
+// Endpoints:                          129
 // Async Callback (empty):               1
-// Total number of exported functions:  23
+// Total number of exported functions: 132
 
 #![no_std]
 
@@ -18,19 +19,37 @@ multiversx_sc_wasm_adapter::endpoints! {
     multiversx_price_aggregator_sc
     (
         init => init
+        upgrade => upgrade
         changeAmounts => change_amounts
+        setStakingTokenWeight => set_staking_token_weight
+        removeStakingToken => remove_staking_token
         addOracles => add_oracles
         removeOracles => remove_oracles
+        setPairOracles => set_pair_oracles
+        clearPairOracles => clear_pair_oracles
+        getPairOracles => get_pair_oracles
         submit => submit
+        setSubmissionUpdatesEnabled => set_submission_updates_enabled
         submitBatch => submit_batch
+        submitBatchWithConfidence => submit_batch_with_confidence
+        setPairRoundWindow => set_pair_round_window
+        clearPairRoundWindow => clear_pair_round_window
+        finalizeRound => finalize_round
+        getPairRoundWindow => get_pair_round_window
         latestRoundData => latest_round_data
+        latestRoundDataPaginated => latest_round_data_paginated
+        latestPriceFeeds => latest_price_feeds
         latestPriceFeed => latest_price_feed
+        latestPriceFeedWithSource => latest_price_feed_with_source
+        getPriceFeedPaid => get_price_feed_paid
+        latestPriceFeedWithFallback => latest_price_feed_with_fallback
         latestPriceFeedOptional => latest_price_feed_optional
         setSubmissionCount => set_submission_count
+        executeAdminAction => execute_admin_action
+        executeTimelockedAction => execute_timelocked_action
         getOracles => get_oracles
         setPairDecimals => set_pair_decimals
         getPairDecimals => get_pair_decimals
-        submission_count => submission_count
         pause => pause_endpoint
         unpause => unpause_endpoint
         isPaused => paused_status
@@ -39,6 +58,97 @@ multiversx_sc_wasm_adapter::endpoints! {
         voteSlashMember => vote_slash_member
         cancelVoteSlashMember => cancel_vote_slash_member
         slashMember => slash_member
+        getStakedAmount => get_staked_amount
+        getBoardMembers => get_board_members
+        getStakingTokens => get_staking_tokens
+        getStakedTokenAmount => staked_token_amount
+        submission_count => submission_count
+        getSubmissionUpdatesEnabled => submission_updates_enabled
+        proposeKeyRotation => propose_key_rotation
+        cancelKeyRotation => cancel_key_rotation
+        acceptKeyRotation => accept_key_rotation
+        getPendingKeyRotation => pending_key_rotation
+        applyAsOracle => apply_as_oracle
+        approveOracleApplication => approve_oracle_application
+        rejectOracleApplication => reject_oracle_application
+        voteOracleApplication => vote_oracle_application
+        getOracleApplications => get_oracle_applications
+        getApplicationVoteCount => get_application_vote_count
+        setGovernanceConfig => set_governance_config
+        proposeAdminAction => propose_admin_action
+        voteAdminAction => vote_admin_action
+        cancelVoteAdminAction => cancel_vote_admin_action
+        getProposalVoteCount => get_proposal_vote_count
+        getGovernanceQuorum => governance_quorum
+        getVotingPeriodSeconds => voting_period_seconds
+        getProposal => proposals
+        setTimelockDelay => set_timelock_delay
+        cancelTimelockedAction => cancel_timelocked_action
+        getTimelockDelay => timelock_delay_seconds
+        getTimelockedAction => timelocked_actions
+        grantRole => grant_role
+        revokeRole => revoke_role
+        emergencyPause => emergency_pause
+        hasRole => has_role
+        getRoleMembers => get_role_members
+        getStorageVersion => storage_version
+        setCircuitBreaker => set_circuit_breaker
+        releaseHeldPrice => release_held_price
+        getHeldPrice => get_held_price
+        getCircuitBreakerConfig => circuit_breaker_config
+        setManualPrice => set_manual_price
+        clearManualPrice => clear_manual_price
+        latestPriceSource => latest_price_source
+        getManualPrice => get_manual_price
+        setPairFallback => set_pair_fallback
+        clearPairFallback => clear_pair_fallback
+        getPairFallback => get_pair_fallback
+        latestRoundDataV3 => latest_round_data_v3
+        getRoundData => get_round_data
+        decimals => decimals
+        description => description
+        version => version
+        getPendingRound => get_pending_round
+        hasSubmitted => has_submitted
+        setPairUpdatePolicy => set_pair_update_policy
+        clearPairUpdatePolicy => clear_pair_update_policy
+        shouldUpdate => should_update
+        getPairUpdatePolicy => get_pair_update_policy
+        createBasket => create_basket
+        removeBasket => remove_basket
+        getBasketPrice => get_basket_price
+        getBasket => get_basket
+        getBasketNames => get_basket_names
+        setEmaHalfLives => set_ema_half_lives
+        setVolatilityWindow => set_volatility_window
+        getEma => get_ema
+        getRealizedVolatility => get_realized_volatility
+        getEmaHalfLives => ema_half_lives
+        getVolatilityWindow => volatility_window
+        setAccessFeeToken => set_access_fee_token
+        setPairAccessFee => set_pair_access_fee
+        claimRevenue => claim_revenue
+        withdrawUnclaimableRevenue => withdraw_unclaimable_revenue
+        getClaimableRevenue => get_claimable_revenue
+        getPairAccessFee => get_pair_access_fee
+        getAccessFeeToken => access_fee_token
+        getEpochRevenue => epoch_revenue
+        setPairMaxConfidence => set_pair_max_confidence
+        clearPairMaxConfidence => clear_pair_max_confidence
+        getRoundConfidence => get_round_confidence
+        getPairMaxConfidence => get_pair_max_confidence
+        setPairMetadata => set_pair_metadata
+        clearPairMetadata => clear_pair_metadata
+        getPairMetadata => get_pair_metadata
+        getPairsForToken => get_pairs_for_token
+        subscribe => subscribe
+        approveSubscription => approve_subscription
+        removeSubscription => remove_subscription
+        unsubscribe => unsubscribe
+        setSubscriptionFee => set_subscription_fee
+        getSubscription => get_subscription
+        getSubscribers => get_subscribers
+        getSubscriptionFee => subscription_fee
     )
 }
 
@@ -0,0 +1,112 @@
+#![no_std]
+
+use multiversx_sc::derive_imports::*;
+use multiversx_sc::imports::*;
+
+pub mod price_aggregator_proxy;
+
+const PRICE_AGGREGATOR_NOT_SET_ERROR: &[u8] = b"price aggregator not set";
+
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, Clone, Debug, PartialEq, Eq)]
+pub struct PriceFeed<M: ManagedTypeApi> {
+    pub round_id: u32,
+    pub from: ManagedBuffer<M>,
+    pub to: ManagedBuffer<M>,
+    pub timestamp: u64,
+    pub price: BigUint<M>,
+    pub decimals: u8,
+}
+
+/// Reads prices from a price aggregator deployed in the same shard.
+/// The contract mixing it in decides who can call `set_price_aggregator`.
+#[multiversx_sc::module]
+pub trait PriceConsumerModule {
+    fn set_price_aggregator(&self, address: ManagedAddress, max_age_seconds: u64) {
+        require!(
+            self.blockchain().is_smart_contract(&address),
+            "price aggregator must be a smart contract"
+        );
+        require!(max_age_seconds > 0, "invalid max age");
+
+        self.price_aggregator_address().set(address);
+        self.price_feed_max_age_seconds().set(max_age_seconds);
+    }
+
+    /// Fails if the latest price of the pair is older than the configured maximum age.
+    fn get_price_feed(&self, from: &ManagedBuffer, to: &ManagedBuffer) -> PriceFeed<Self::Api> {
+        let aggregator_mapper = self.price_aggregator_address();
+        require!(
+            !aggregator_mapper.is_empty(),
+            PRICE_AGGREGATOR_NOT_SET_ERROR
+        );
+
+        let (round_id, from, to, timestamp, price, decimals) = self
+            .tx()
+            .to(aggregator_mapper.get())
+            .typed(price_aggregator_proxy::PriceAggregatorProxy)
+            .latest_price_feed(from, to)
+            .returns(ReturnsResult)
+            .sync_call()
+            .into_tuple();
+
+        let current_timestamp = self.blockchain().get_block_timestamp();
+        require!(
+            current_timestamp.saturating_sub(timestamp) <= self.price_feed_max_age_seconds().get(),
+            "price feed is stale"
+        );
+
+        PriceFeed {
+            round_id,
+            from,
+            to,
+            timestamp,
+            price,
+            decimals,
+        }
+    }
+
+    /// Converts an amount of `from` into `to` using the price of the `from`/`to` pair.
+    /// The decimals are the ones of the token amounts, not of the price.
+    fn convert_amount(
+        &self,
+        amount: &BigUint,
+        from: &ManagedBuffer,
+        from_decimals: u8,
+        to: &ManagedBuffer,
+        to_decimals: u8,
+    ) -> BigUint {
+        let price_feed = self.get_price_feed(from, to);
+
+        amount * &price_feed.price * pow10(to_decimals)
+            / (pow10(price_feed.decimals) * pow10(from_decimals))
+    }
+
+    /// Same as `convert_amount`, for when the aggregator only has the `to`/`from` pair.
+    fn convert_amount_by_inverse_pair(
+        &self,
+        amount: &BigUint,
+        from: &ManagedBuffer,
+        from_decimals: u8,
+        to: &ManagedBuffer,
+        to_decimals: u8,
+    ) -> BigUint {
+        let price_feed = self.get_price_feed(to, from);
+        require!(price_feed.price > 0u64, "invalid price");
+
+        amount * &pow10(price_feed.decimals) * pow10(to_decimals)
+            / (price_feed.price * pow10(from_decimals))
+    }
+
+    #[view(getPriceAggregatorAddress)]
+    #[storage_mapper("price_consumer:aggregator_address")]
+    fn price_aggregator_address(&self) -> SingleValueMapper<ManagedAddress>;
+
+    #[view(getPriceFeedMaxAgeSeconds)]
+    #[storage_mapper("price_consumer:max_age_seconds")]
+    fn price_feed_max_age_seconds(&self) -> SingleValueMapper<u64>;
+}
+
+fn pow10<M: ManagedTypeApi>(decimals: u8) -> BigUint<M> {
+    BigUint::from(10u64).pow(decimals as u32)
+}